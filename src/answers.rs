@@ -0,0 +1,135 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, read_to_string},
+    path::Path,
+};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use toml_edit::{DocumentMut, table, value};
+
+use crate::days::Answer;
+
+pub const ANSWERS_PATH: &str = "answers.toml";
+
+// answers can be written as TOML integers or as strings (for answers that aren't numbers or don't fit an i64)
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AnswerValue {
+    Integer(i64),
+    Text(String),
+}
+
+impl AnswerValue {
+    fn to_answer(&self) -> Answer {
+        match self {
+            AnswerValue::Integer(answer) => Answer::Int(*answer),
+            AnswerValue::Text(answer) => Answer::parse(answer),
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+struct PartAnswers {
+    part_one: Option<AnswerValue>,
+    part_two: Option<AnswerValue>,
+}
+
+#[derive(Deserialize, Default)]
+struct DayAnswers {
+    part_one: Option<AnswerValue>,
+    part_two: Option<AnswerValue>,
+    example: Option<PartAnswers>,
+}
+
+/// Accepted answers, read from answers.toml. Each day has its own table within its year:
+///
+/// ```toml
+/// [2025.day08]
+/// part_one = 123
+/// part_two = 456
+///
+/// [2025.day08.example]
+/// part_one = 40
+/// part_two = 25272
+/// ```
+#[derive(Default)]
+pub struct Answers {
+    years: BTreeMap<String, BTreeMap<String, DayAnswers>>,
+}
+
+impl Answers {
+    pub fn load() -> Result<Answers> {
+        if !Path::new(ANSWERS_PATH).exists() {
+            return Ok(Answers::default());
+        }
+
+        let answers_str = read_to_string(ANSWERS_PATH)
+            .with_context(|| format!("Couldn't read {ANSWERS_PATH}"))?;
+        let years = toml::from_str(&answers_str)
+            .with_context(|| format!("Couldn't parse {ANSWERS_PATH}"))?;
+
+        Ok(Answers { years })
+    }
+
+    pub fn expected(&self, year: i32, day: u32, part: u8, example: bool) -> Option<Answer> {
+        let day_answers = self
+            .years
+            .get(&year.to_string())?
+            .get(&format!("day{day:02}"))?;
+        let answer = match (example, part) {
+            (false, 1) => &day_answers.part_one,
+            (false, 2) => &day_answers.part_two,
+            (true, 1) => &day_answers.example.as_ref()?.part_one,
+            (true, 2) => &day_answers.example.as_ref()?.part_two,
+            _ => &None,
+        };
+
+        answer.as_ref().map(AnswerValue::to_answer)
+    }
+
+    pub fn has_day(&self, year: i32, day: u32, example: bool) -> bool {
+        (1..=2).any(|part| self.expected(year, day, part, example).is_some())
+    }
+}
+
+// adds an accepted answer to answers.toml, keeping the rest of the file as it is
+pub fn record(year: i32, day: u32, part: u8, answer: &str) -> Result<()> {
+    let mut document = match Path::new(ANSWERS_PATH).exists() {
+        true => read_to_string(ANSWERS_PATH)
+            .with_context(|| format!("Couldn't read {ANSWERS_PATH}"))?
+            .parse::<DocumentMut>()
+            .with_context(|| format!("Couldn't parse {ANSWERS_PATH}"))?,
+        false => DocumentMut::new(),
+    };
+
+    let year_table = document
+        .entry(&year.to_string())
+        .or_insert(table())
+        .as_table_mut()
+        .with_context(|| format!("{year} in {ANSWERS_PATH} is not a table"))?;
+    // only the days' tables get a header
+    if year_table.is_empty() {
+        year_table.set_implicit(true);
+    }
+
+    let day_table = year_table
+        .entry(&format!("day{day:02}"))
+        .or_insert(table())
+        .as_table_mut()
+        .with_context(|| format!("{year}.day{day:02} in {ANSWERS_PATH} is not a table"))?;
+    // the day's table is implicit if only its example answers were recorded so far
+    day_table.set_implicit(false);
+
+    let part_key = match part {
+        1 => "part_one",
+        _ => "part_two",
+    };
+    day_table[part_key] = match answer.parse::<i64>() {
+        Ok(answer) => value(answer),
+        Err(_) => value(answer),
+    };
+
+    fs::write(ANSWERS_PATH, document.to_string())
+        .with_context(|| format!("Couldn't write to {ANSWERS_PATH}"))
+}
@@ -0,0 +1,146 @@
+use std::sync::OnceLock;
+
+use anyhow::{Result, bail};
+use chrono::{DateTime, Datelike, FixedOffset, TimeDelta, TimeZone, Utc};
+
+// the first Advent of Code event
+pub const FIRST_YEAR: i32 = 2015;
+// the number of days of the longest events, used to validate days before the year is known
+pub const MAX_DAY: u32 = 25;
+
+// set by --now, to see what happens at a different time. The clock keeps running from there, so waiting works too
+static NOW_OFFSET: OnceLock<TimeDelta> = OnceLock::new();
+
+// the events had 25 days until 2024, and have 12 since 2025
+pub fn last_day(year: i32) -> u32 {
+    match year {
+        ..2025 => 25,
+        _ => 12,
+    }
+}
+
+// puzzles unlock at midnight US Eastern Standard Time, which is UTC-5 all through December
+pub fn release_timezone() -> FixedOffset {
+    FixedOffset::west_opt(5 * 3600).unwrap()
+}
+
+pub fn unlock_time(year: i32, day: u32) -> DateTime<Utc> {
+    release_timezone()
+        .with_ymd_and_hms(year, 12, day, 0, 0, 0)
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+// the first day of the event that isn't unlocked yet at the given time, if any
+pub fn next_unlock(year: i32, now: DateTime<Utc>) -> Option<u32> {
+    (1..=last_day(year)).find(|day| unlock_time(year, *day) > now)
+}
+
+// e.g. "2d 03:04:05", or "03:04:05" for less than a day
+pub fn format_countdown(remaining: TimeDelta) -> String {
+    let seconds = remaining.num_seconds();
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+
+    match seconds / 86400 {
+        0 => time,
+        days => format!("{days}d {time}"),
+    }
+}
+
+pub fn override_now(now: DateTime<Utc>) {
+    NOW_OFFSET.get_or_init(|| now - Utc::now());
+}
+
+pub fn now() -> DateTime<Utc> {
+    Utc::now() + NOW_OFFSET.get().copied().unwrap_or_default()
+}
+
+/// The day of the event whose puzzle was released most recently, going by the release timezone rather than the
+/// local one. Outside the event, the error says how long it is until the next unlock.
+pub fn today(year: i32) -> Result<u32> {
+    today_at(year, now())
+}
+
+fn today_at(year: i32, now: DateTime<Utc>) -> Result<u32> {
+    let date = now.with_timezone(&release_timezone());
+    if date.year() == year && date.month() == 12 && date.day() <= last_day(year) {
+        return Ok(date.day());
+    }
+
+    match next_unlock(year, now) {
+        Some(day) => bail!(
+            "Day {day} of AoC {year} unlocks in {}",
+            format_countdown(unlock_time(year, day) - now)
+        ),
+        None => bail!("AoC {year} is over"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(time: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(time)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn puzzles_unlock_at_midnight_eastern() {
+        assert_eq!(unlock_time(2025, 1), utc("2025-12-01T05:00:00Z"));
+        assert_eq!(unlock_time(2024, 25), utc("2024-12-25T05:00:00Z"));
+    }
+
+    #[test]
+    fn days_per_year() {
+        assert_eq!(last_day(2015), 25);
+        assert_eq!(last_day(2024), 25);
+        assert_eq!(last_day(2025), 12);
+    }
+
+    #[test]
+    fn next_unlock_is_the_first_locked_day() {
+        assert_eq!(next_unlock(2025, utc("2025-11-20T12:00:00Z")), Some(1));
+        assert_eq!(next_unlock(2025, utc("2025-12-04T04:59:59Z")), Some(4));
+        assert_eq!(next_unlock(2025, utc("2025-12-04T05:00:00Z")), Some(5));
+        assert_eq!(next_unlock(2025, utc("2025-12-12T05:00:00Z")), None);
+    }
+
+    #[test]
+    fn today_goes_by_the_release_timezone() {
+        // still the evening of the 3rd in the release timezone
+        assert_eq!(today_at(2025, utc("2025-12-04T04:59:59Z")).unwrap(), 3);
+        assert_eq!(today_at(2025, utc("2025-12-04T05:00:00Z")).unwrap(), 4);
+        assert_eq!(today_at(2025, utc("2025-12-13T04:59:59Z")).unwrap(), 12);
+    }
+
+    #[test]
+    fn no_puzzle_today_outside_the_event() {
+        let before = today_at(2025, utc("2025-11-30T05:00:00Z")).unwrap_err();
+        assert_eq!(
+            before.to_string(),
+            "Day 1 of AoC 2025 unlocks in 1d 00:00:00"
+        );
+
+        let after = today_at(2025, utc("2025-12-13T05:00:00Z")).unwrap_err();
+        assert_eq!(after.to_string(), "AoC 2025 is over");
+        // the other year's event doesn't count
+        assert!(today_at(2024, utc("2025-12-04T05:00:00Z")).is_err());
+    }
+
+    #[test]
+    fn countdowns() {
+        assert_eq!(format_countdown(TimeDelta::zero()), "00:00:00");
+        assert_eq!(format_countdown(TimeDelta::seconds(3661)), "01:01:01");
+        assert_eq!(
+            format_countdown(TimeDelta::seconds(2 * 86400 + 3 * 3600 + 4 * 60 + 5)),
+            "2d 03:04:05"
+        );
+    }
+}
@@ -0,0 +1,338 @@
+use std::{
+    env,
+    fs::read_to_string,
+    sync::{Mutex, OnceLock},
+    thread::sleep,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result, bail};
+use regex::Regex;
+use reqwest::{
+    StatusCode,
+    blocking::{Client, RequestBuilder},
+    header::COOKIE,
+};
+
+use crate::config::{CONFIG_PATH, Config, LOCAL_CONFIG_PATH, LocalConfig};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const TOKEN_PATH: &str = ".token";
+// AoC asks automated tools to identify themselves, see user_agent in aoc.toml to add your contact details
+const DEFAULT_USER_AGENT: &str = concat!(
+    "aoc/",
+    env!("CARGO_PKG_VERSION"),
+    " (github.com/Scyak/aoc-template)"
+);
+// the least time between two requests to AoC
+const REQUEST_INTERVAL: Duration = Duration::from_secs(2);
+// how often a request that failed with a server error is retried, waiting twice as long each time
+const MAX_RETRIES: u32 = 3;
+const FIRST_RETRY_DELAY: Duration = Duration::from_secs(2);
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+// shared by every client, so requests are throttled across the whole run
+static LAST_REQUEST: Mutex<Option<Instant>> = Mutex::new(None);
+// set by on_retry, retries aren't reported otherwise
+static RETRY_HANDLER: OnceLock<fn(&Retry)> = OnceLock::new();
+
+/// A request that failed in a way that's worth trying again, and how long the client waits before it does
+pub struct Retry<'a> {
+    pub cause: RetryCause<'a>,
+    pub delay: Duration,
+}
+
+pub enum RetryCause<'a> {
+    // e.g. a timeout
+    Unreachable(&'a reqwest::Error),
+    ServerError(StatusCode),
+}
+
+/// Sets the function every client calls before it waits to retry a request, e.g. to tell the user about it
+pub fn on_retry(handler: fn(&Retry)) {
+    RETRY_HANDLER.get_or_init(|| handler);
+}
+
+/// Finds the session token, which is taken from the first of these that is set:
+/// the `AOC_SESSION` environment variable, `session` in aoc.local.toml, or the .token file.
+/// Returns the token and where it was found.
+pub fn session_token() -> Result<(String, String)> {
+    let non_empty =
+        |token: String| Some(token.trim().to_string()).filter(|token| !token.is_empty());
+
+    if let Some(token) = env::var("AOC_SESSION").ok().and_then(non_empty) {
+        return Ok((token, "the AOC_SESSION environment variable".to_string()));
+    }
+    let config =
+        LocalConfig::load().with_context(|| format!("Couldn't read {LOCAL_CONFIG_PATH}"))?;
+    if let Some(token) = config.session.and_then(non_empty) {
+        return Ok((token, LOCAL_CONFIG_PATH.to_string()));
+    }
+    // a trailing newline would break the cookie, so the file is trimmed like the other sources
+    match read_to_string(TOKEN_PATH).ok().and_then(non_empty) {
+        Some(token) => Ok((token, format!("the {TOKEN_PATH} file"))),
+        None => bail!(
+            "No session token found. Set the AOC_SESSION environment variable, add session to {LOCAL_CONFIG_PATH} or put it in the {TOKEN_PATH} file"
+        ),
+    }
+}
+
+fn report_retry(cause: RetryCause, delay: Duration) {
+    if let Some(handler) = RETRY_HANDLER.get() {
+        handler(&Retry { cause, delay });
+    }
+}
+
+// pages show the logged in user's name at the top, and a link to log in otherwise
+fn is_login_page(page: &str) -> bool {
+    page.contains("/auth/login") && !page.contains(r#"<div class="user">"#)
+}
+
+/// Talks to the AoC website. Set `AOC_BASE_URL` to point it at a local stand-in server instead.
+pub struct AocClient {
+    client: Client,
+    base_url: String,
+    token: String,
+    request_interval: Duration,
+    first_retry_delay: Duration,
+}
+
+impl AocClient {
+    pub fn new() -> Result<AocClient> {
+        let (token, _) = session_token()?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or(DEFAULT_BASE_URL.to_string());
+        let user_agent = Config::load()
+            .with_context(|| format!("Couldn't read {CONFIG_PATH}"))?
+            .user_agent
+            .unwrap_or(DEFAULT_USER_AGENT.to_string());
+
+        AocClient::with_base_url(&base_url, &token, &user_agent)
+    }
+
+    pub fn with_base_url(base_url: &str, token: &str, user_agent: &str) -> Result<AocClient> {
+        Ok(AocClient {
+            client: Client::builder()
+                .user_agent(user_agent)
+                .timeout(TIMEOUT)
+                .build()
+                .context("Couldn't create the HTTP client")?,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            // a trailing newline would break the cookie
+            token: token.trim().to_string(),
+            request_interval: REQUEST_INTERVAL,
+            first_retry_delay: FIRST_RETRY_DELAY,
+        })
+    }
+
+    /// Replaces the least time between two requests and the delay before the first retry, e.g. to not wait
+    /// on a local stand-in server
+    pub fn with_delays(self, request_interval: Duration, first_retry_delay: Duration) -> AocClient {
+        AocClient {
+            request_interval,
+            first_retry_delay,
+            ..self
+        }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    // waits until the request interval has passed since the last request
+    fn throttle(&self) {
+        let mut last_request = LAST_REQUEST.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(wait) =
+            last_request.and_then(|last| self.request_interval.checked_sub(last.elapsed()))
+        {
+            sleep(wait);
+        }
+        *last_request = Some(Instant::now());
+    }
+
+    /// Sends the request built by request and returns the response's body. Server errors and timeouts are retried
+    /// up to retries times, other errors are returned with an explanation of the likely cause.
+    fn send(&self, request: impl Fn() -> RequestBuilder, retries: u32) -> Result<String> {
+        let mut delay = self.first_retry_delay;
+        let mut attempt = 0;
+        loop {
+            self.throttle();
+            let can_retry = attempt < retries;
+            attempt += 1;
+
+            let response = match request()
+                .header(COOKIE, format!("session={};", self.token))
+                .send()
+            {
+                Ok(response) => response,
+                Err(e) if (e.is_timeout() || e.is_connect()) && can_retry => {
+                    report_retry(RetryCause::Unreachable(&e), delay);
+                    sleep(delay);
+                    delay *= 2;
+                    continue;
+                }
+                Err(e) => return Err(e).context("Couldn't reach AoC"),
+            };
+
+            match response.status() {
+                status if status.is_success() => {
+                    return response
+                        .text()
+                        .context("Failed to parse the website's response");
+                }
+                StatusCode::NOT_FOUND => {
+                    bail!("AoC responded with 404 Not Found. Is the puzzle unlocked yet?")
+                }
+                StatusCode::BAD_REQUEST => bail!(
+                    "AoC responded with 400 Bad Request. Your session token is probably invalid or expired, check it with token check"
+                ),
+                status if status.is_server_error() && can_retry => {
+                    report_retry(RetryCause::ServerError(status), delay);
+                    sleep(delay);
+                    delay *= 2;
+                }
+                status if status.is_server_error() => {
+                    bail!("AoC responded with {status}, giving up after {retries} retries")
+                }
+                status => bail!("AoC responded with {status}"),
+            }
+        }
+    }
+
+    pub fn fetch_input(&self, year: i32, day: u32) -> Result<String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+
+        self.send(|| self.client.get(&url), MAX_RETRIES)
+            .context("Couldn't retrieve input from AoC website")
+    }
+
+    // returns the HTML of the puzzle's page, which contains the description of every unlocked part
+    pub fn fetch_puzzle(&self, year: i32, day: u32) -> Result<String> {
+        let url = format!("{}/{year}/day/{day}", self.base_url);
+
+        let page = self
+            .send(|| self.client.get(&url), MAX_RETRIES)
+            .context("Couldn't retrieve puzzle from AoC website")?;
+        // the puzzle is shown without logging in, but only part one
+        if is_login_page(&page) {
+            bail!(
+                "AoC isn't accepting your session token, it has probably expired. Check it with token check"
+            );
+        }
+
+        Ok(page)
+    }
+
+    // returns the name of the user the session token belongs to, or an error if it isn't accepted
+    pub fn logged_in_user(&self, year: i32) -> Result<String> {
+        let user_re = Regex::new(r#"<div class="user">([^<]*)"#).unwrap();
+
+        let url = format!("{}/{year}", self.base_url);
+        let page = self
+            .send(|| self.client.get(&url), MAX_RETRIES)
+            .context("Couldn't retrieve the calendar from AoC website")?;
+
+        match user_re.captures(&page) {
+            Some(user) => Ok(user[1].trim().to_string()),
+            None => bail!("AoC isn't accepting the session token, it is invalid or has expired"),
+        }
+    }
+
+    // returns the HTML of the page AoC responds with, which contains the verdict
+    // never retried, since AoC might have received the answer despite the error
+    pub fn post_answer(&self, year: i32, day: u32, part: u8, answer: &str) -> Result<String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let form = [("level", part.to_string()), ("answer", answer.to_string())];
+
+        let page = self
+            .send(|| self.client.post(&url).form(&form), 0)
+            .context("Couldn't submit answer to AoC website")?;
+        if is_login_page(&page) {
+            bail!(
+                "AoC isn't accepting your session token, it has probably expired. Check it with token check"
+            );
+        }
+
+        Ok(page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::test_server::{StubServer, TOKEN, USER_AGENT};
+
+    #[test]
+    fn sends_user_agent_and_trimmed_cookie() {
+        let server = StubServer::serve(vec![(200, "L68\nR48\n")]);
+
+        let input = server.client().fetch_input(2025, 1).unwrap();
+
+        assert_eq!(input, "L68\nR48\n");
+        let requests = server.requests();
+        let request = requests[0].to_lowercase();
+        assert!(request.starts_with("get /2025/day/1/input http/1.1\r\n"));
+        assert!(request.contains(&format!("\r\nuser-agent: {USER_AGENT}\r\n")));
+        assert!(request.contains(&format!("\r\ncookie: session={TOKEN};\r\n")));
+    }
+
+    #[test]
+    fn not_found_means_locked() {
+        let server = StubServer::serve(vec![(404, "")]);
+
+        let e = server.client().fetch_input(2025, 1).unwrap_err();
+
+        assert!(format!("{e:#}").contains("404 Not Found. Is the puzzle unlocked yet?"));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn bad_request_means_invalid_token() {
+        let server = StubServer::serve(vec![(400, "")]);
+
+        let e = server.client().fetch_input(2025, 1).unwrap_err();
+
+        assert!(format!("{e:#}").contains("Your session token is probably invalid or expired"));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn server_errors_are_retried() {
+        // other tests retry too, so only the server errors are counted
+        static SERVER_ERRORS: AtomicUsize = AtomicUsize::new(0);
+        on_retry(|retry| {
+            if let RetryCause::ServerError(StatusCode::SERVICE_UNAVAILABLE) = retry.cause {
+                SERVER_ERRORS.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+        let server = StubServer::serve(vec![(500, ""), (503, ""), (200, "L68\n")]);
+
+        let input = server.client().fetch_input(2025, 1).unwrap();
+
+        assert_eq!(input, "L68\n");
+        assert_eq!(server.requests().len(), 3);
+        assert_eq!(SERVER_ERRORS.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn gives_up_after_max_retries() {
+        let server = StubServer::serve(vec![(500, ""); MAX_RETRIES as usize + 1]);
+
+        let e = server.client().fetch_input(2025, 1).unwrap_err();
+
+        assert!(format!("{e:#}").contains(&format!(
+            "500 Internal Server Error, giving up after {MAX_RETRIES} retries"
+        )));
+        assert_eq!(server.requests().len(), MAX_RETRIES as usize + 1);
+    }
+
+    #[test]
+    fn login_page_means_expired_token() {
+        let server = StubServer::serve(vec![(200, r#"<a href="/auth/login">[Log In]</a>"#)]);
+
+        let e = server.client().fetch_puzzle(2025, 1).unwrap_err();
+
+        assert!(format!("{e:#}").contains("AoC isn't accepting your session token"));
+    }
+}
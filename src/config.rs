@@ -0,0 +1,75 @@
+use std::{fs::read_to_string, path::Path};
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, de::DeserializeOwned};
+
+use crate::calendar::FIRST_YEAR;
+
+pub const CONFIG_PATH: &str = "aoc.toml";
+// for settings that mustn't be committed, .gitignore keeps it out of the repository
+pub const LOCAL_CONFIG_PATH: &str = "aoc.local.toml";
+
+/// Settings read from aoc.toml. Every setting is optional:
+///
+/// ```toml
+/// # the event to use when --year isn't passed
+/// year = 2024
+/// # sent with every request to AoC, which asks for a way to contact you
+/// user_agent = "github.com/you/your-aoc-repo by you@example.com"
+/// # where inputs are saved, with a directory for each year (input by default)
+/// input_dir = "../aoc-inputs"
+/// ```
+#[derive(Deserialize, Default)]
+pub struct Config {
+    pub year: Option<i32>,
+    pub user_agent: Option<String>,
+    pub input_dir: Option<String>,
+    // only read to point out that it belongs in aoc.local.toml
+    session: Option<toml::Value>,
+}
+
+/// Settings read from aoc.local.toml, which isn't committed:
+///
+/// ```toml
+/// # the session cookie from adventofcode.com, if it's not in AOC_SESSION or the .token file
+/// session = "53616c7465645f5f..."
+/// ```
+#[derive(Deserialize, Default)]
+pub struct LocalConfig {
+    pub session: Option<String>,
+}
+
+// a missing file has every setting unset
+fn read<T: DeserializeOwned + Default>(path: &str) -> Result<T> {
+    if !Path::new(path).exists() {
+        return Ok(T::default());
+    }
+
+    let config_str = read_to_string(path).with_context(|| format!("Couldn't read {path}"))?;
+    toml::from_str(&config_str).with_context(|| format!("Couldn't parse {path}"))
+}
+
+impl Config {
+    pub fn load() -> Result<Config> {
+        let config: Config = read(CONFIG_PATH)?;
+
+        if let Some(year) = config.year
+            && year < FIRST_YEAR
+        {
+            bail!("The year in {CONFIG_PATH} must be {FIRST_YEAR} or later, not {year}");
+        }
+        if config.session.is_some() {
+            bail!(
+                "{CONFIG_PATH} is meant to be committed, so the session token doesn't belong in it. Move it to {LOCAL_CONFIG_PATH}"
+            );
+        }
+
+        Ok(config)
+    }
+}
+
+impl LocalConfig {
+    pub fn load() -> Result<LocalConfig> {
+        read(LOCAL_CONFIG_PATH)
+    }
+}
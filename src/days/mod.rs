@@ -1,54 +1,215 @@
-use std::{fmt::Display, fs::read_to_string, time::Instant};
-
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
+use std::{
+    any::Any,
+    fmt::{self, Display},
+};
+
+use anyhow::{Context, Result};
+
+/// What a part returns. Integers are kept as numbers, so answers can be compared numerically,
+/// and parts without an answer say so instead of returning a message that looks like one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    // for answers that don't fit an i64
+    BigInt(i128),
+    Text(String),
+    // the template's answer, for parts that haven't been solved yet
+    Unsolved,
+    // for parts without a puzzle to solve, like the last day's part two
+    NotApplicable,
+}
+
+impl Answer {
+    // whether this is an actual answer, which can be submitted and recorded
+    pub fn is_answer(&self) -> bool {
+        matches!(self, Answer::Int(_) | Answer::BigInt(_) | Answer::Text(_))
+    }
+
+    /// Reads an answer written down elsewhere, e.g. in answers.toml. Anything that looks like an integer becomes
+    /// one, so it compares equal to the same integer returned by a part.
+    pub fn parse(answer: &str) -> Answer {
+        let answer = answer.trim();
+        match (answer.parse::<i64>(), answer.parse::<i128>()) {
+            (Ok(answer), _) => Answer::Int(answer),
+            (_, Ok(answer)) => Answer::BigInt(answer),
+            _ => Answer::Text(answer.to_string()),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(answer) => write!(f, "{answer}"),
+            Answer::BigInt(answer) => write!(f, "{answer}"),
+            Answer::Text(answer) => write!(f, "{answer}"),
+            Answer::Unsolved => write!(f, "no solution yet :("),
+            Answer::NotApplicable => write!(f, "no answer needed"),
+        }
+    }
+}
+
+// integers that fit an i64 are always Int, so equal answers are equal regardless of the part's integer type
+macro_rules! answer_from_int {
+    ($($int:ty),*) => {
+        $(impl From<$int> for Answer {
+            fn from(answer: $int) -> Answer {
+                match (i64::try_from(answer), i128::try_from(answer)) {
+                    (Ok(answer), _) => Answer::Int(answer),
+                    (_, Ok(answer)) => Answer::BigInt(answer),
+                    _ => Answer::Text(answer.to_string()),
+                }
+            }
+        })*
+    };
+}
+
+answer_from_int!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Answer {
+        Answer::Text(answer)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(answer: &str) -> Answer {
+        Answer::Text(answer.to_string())
+    }
+}
+
+/// Adds the number and text of the input line that caused an error, for errors while parsing line by line
+pub trait LineContext<T, E> {
+    // index is the 0-based index from lines().enumerate()
+    fn line_context(self, index: usize, line: &str) -> Result<T>;
+}
+
+impl<T, E, C: Context<T, E>> LineContext<T, E> for C {
+    fn line_context(self, index: usize, line: &str) -> Result<T> {
+        self.with_context(|| format!("Couldn't parse line {}: {line:?}", index + 1))
+    }
+}
 
 pub trait Day {
-    fn part_one(input: &str) -> impl Display;
-    fn part_two(input: &str) -> impl Display;
+    // what the input is parsed into, once for both parts. The runner may solve the parts on other threads
+    type Parsed: Send + Sync + 'static;
+    fn parse(input: &str) -> Result<Self::Parsed>;
+    // parses the example input, for days whose puzzle works differently on the example, e.g. with a smaller limit
+    fn parse_example(input: &str) -> Result<Self::Parsed> {
+        Self::parse(input)
+    }
+    fn part_one(parsed: &Self::Parsed) -> Result<impl Into<Answer>>;
+    fn part_two(parsed: &Self::Parsed) -> Result<impl Into<Answer>>;
     fn get_day_num() -> u8;
-    fn run() {
-        let day = Self::get_day_num();
-
-        println!("--- Day {} ---", day);
-        let path_str = format!("input/day{:0>2}.txt", day);
-        let input = match read_to_string(&path_str) {
-            Ok(input) => input,
-            Err(_) => {
-                println!(
-                    "Couldn't read input file for day {}. Not running this day.",
-                    day
-                );
-                return;
-            }
-        };
+    fn example_input() -> Option<&'static str> {
+        None
+    }
+    // only needs to be implemented if part two's example differs from part one's
+    fn example_input_part_two() -> Option<&'static str> {
+        Self::example_input()
+    }
+    // the puzzle's title
+    fn get_name() -> &'static str;
+    // a regex that every line of the input matches, so fetched inputs that are broken can be told apart from inputs
+    fn input_pattern() -> Option<&'static str> {
+        None
+    }
+}
+
+// part one's and part two's answer, as found by solve_both
+pub type AnswerPair = (Answer, Answer);
+
+// a day's parsed input, whatever its type
+pub type ParsedInput = dyn Any + Send + Sync;
 
-        println!();
+/// A day's entry in the registry, with the trait's functions turned into function pointers.
+/// Every day parses into a different type, so the parsed input is passed around as `ParsedInput`.
+#[derive(Clone, Copy)]
+pub struct DayEntry {
+    pub year: i32,
+    pub day: u8,
+    pub name: &'static str,
+    pub parse: fn(&str) -> Result<Box<ParsedInput>>,
+    pub parse_example: fn(&str) -> Result<Box<ParsedInput>>,
+    pub part_one: fn(&ParsedInput) -> Result<Answer>,
+    pub part_two: fn(&ParsedInput) -> Result<Answer>,
+    // None if the parts are only solved separately, see register_days!
+    pub solve_both: Option<fn(&ParsedInput) -> Result<AnswerPair>>,
+    pub example_input: Option<&'static str>,
+    pub example_input_part_two: Option<&'static str>,
+    pub input_pattern: Option<&'static str>,
+}
+
+impl DayEntry {
+    fn new<D: Day>(year: i32) -> DayEntry {
+        DayEntry {
+            year,
+            day: D::get_day_num(),
+            name: D::get_name(),
+            parse: |input| D::parse(input).map(|parsed| Box::new(parsed) as Box<ParsedInput>),
+            parse_example: |input| {
+                D::parse_example(input).map(|parsed| Box::new(parsed) as Box<ParsedInput>)
+            },
+            part_one: |parsed| D::part_one(downcast::<D>(parsed)?).map(Into::into),
+            part_two: |parsed| D::part_two(downcast::<D>(parsed)?).map(Into::into),
+            solve_both: None,
+            example_input: D::example_input(),
+            example_input_part_two: D::example_input_part_two(),
+            input_pattern: D::input_pattern(),
+        }
+    }
 
-        let start_time_one = Instant::now();
-        println!("- Starting part one -");
-        let result_one = Self::part_one(&input);
-        println!("Result (part one): {result_one}");
-        println!("Elapsed time: {:?}", start_time_one.elapsed());
+    fn with_solve_both(self, solve_both: fn(&ParsedInput) -> Result<AnswerPair>) -> DayEntry {
+        DayEntry {
+            solve_both: Some(solve_both),
+            ..self
+        }
+    }
+}
 
-        println!();
+// parts can only be passed what their own day's parse returned
+fn downcast<D: Day>(parsed: &ParsedInput) -> Result<&D::Parsed> {
+    parsed.downcast_ref().with_context(|| {
+        format!(
+            "Day {} was passed another day's parsed input",
+            D::get_day_num()
+        )
+    })
+}
 
-        let start_time_two = Instant::now();
-        println!("- Starting part two -");
-        let result_two = Self::part_two(&input);
-        println!("Result (part two): {result_two}");
-        println!("Elapsed time: {:?}", start_time_two.elapsed());
+// declares a year's day modules and lists their Day implementations in the year's registry
+// days that find both answers in the same pass name the function that returns both after a +,
+// e.g. `day04::Day04 + solve_both`, which the runner then uses instead of the two parts
+macro_rules! register_days {
+    ($year:literal; $($module:ident::$day:ident $(+ $solve_both:ident)?),* $(,)?) => {
+        $(pub mod $module;)*
 
-        println!();
+        pub fn registry() -> Vec<$crate::days::DayEntry> {
+            vec![$(
+                $crate::days::DayEntry::new::<$module::$day>($year)
+                    $(.with_solve_both(|parsed| {
+                        let parsed = $crate::days::downcast::<$module::$day>(parsed)?;
+                        $module::$solve_both(parsed).map(|(one, two)| (one.into(), two.into()))
+                    }))?
+            ),*]
+        }
+    };
+}
+
+// one module per event, each registering its own days
+pub mod y2025;
+
+pub fn registry(year: i32) -> Vec<DayEntry> {
+    match year {
+        2025 => y2025::registry(),
+        _ => vec![],
     }
 }
+
+pub fn find_day(year: i32, day: u32) -> Option<DayEntry> {
+    registry(year)
+        .into_iter()
+        .find(|entry| u32::from(entry.day) == day)
+}
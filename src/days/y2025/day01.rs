@@ -0,0 +1,89 @@
+use anyhow::{Result, bail};
+
+use crate::days::{Answer, Day, LineContext};
+
+pub struct Day01;
+
+impl Day for Day01 {
+    type Parsed = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(line_num, line)| parse_rotation(line).line_context(line_num, line))
+            .collect()
+    }
+
+    fn part_one(rotations: &Vec<i32>) -> Result<impl Into<Answer>> {
+        let mut position: i32 = 50;
+        let mut result = 0;
+
+        for rotation in rotations {
+            position += rotation;
+
+            while position < 0 {
+                position += 100;
+            }
+
+            while position > 99 {
+                position -= 100;
+            }
+
+            if position == 0 {
+                result += 1;
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn part_two(rotations: &Vec<i32>) -> Result<impl Into<Answer>> {
+        let mut position: i32 = 50;
+        let mut result = 0;
+
+        for rotation in rotations {
+            if position == 0 && *rotation < 0 {
+                position += 100;
+            }
+
+            position += rotation;
+
+            while position < 0 {
+                position += 100;
+                result += 1;
+            }
+
+            if position == 0 {
+                result += 1;
+            }
+
+            while position > 99 {
+                position -= 100;
+                result += 1;
+            }
+        }
+
+        Ok(result)
+    }
+    fn get_day_num() -> u8 {
+        return 1;
+    }
+
+    fn get_name() -> &'static str {
+        "Secret Entrance"
+    }
+
+    fn input_pattern() -> Option<&'static str> {
+        Some(r"^[LR]\d+$")
+    }
+}
+
+// rotations to the right are positive, rotations to the left are negative
+fn parse_rotation(line: &str) -> Result<i32> {
+    match line.split_at_checked(1) {
+        Some(("R", distance)) => Ok(distance.parse()?),
+        Some(("L", distance)) => Ok(-distance.parse::<i32>()?),
+        _ => bail!("Unknown direction"),
+    }
+}
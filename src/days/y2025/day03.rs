@@ -0,0 +1,91 @@
+use anyhow::{Result, bail};
+
+use crate::days::{Answer, Day, LineContext};
+
+pub struct Day03;
+
+const EXAMPLE_INPUT: &str = "987654321111111
+811111111111119
+234234234234278
+818181911112111";
+
+impl Day for Day03 {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(line_num, line)| parse_bank(line).line_context(line_num, line))
+            .collect()
+    }
+
+    fn part_one(banks: &Vec<String>) -> Result<impl Into<Answer>> {
+        let mut sum = 0;
+
+        // banks are the input's lines
+        for (line_num, bank) in banks.iter().enumerate() {
+            sum += find_max_joltage(bank, 2).line_context(line_num, bank)?;
+        }
+
+        Ok(sum)
+    }
+
+    fn part_two(banks: &Vec<String>) -> Result<impl Into<Answer>> {
+        let mut sum = 0;
+
+        for (line_num, bank) in banks.iter().enumerate() {
+            sum += find_max_joltage(bank, 12).line_context(line_num, bank)?;
+        }
+
+        Ok(sum)
+    }
+
+    fn get_day_num() -> u8 {
+        return 3;
+    }
+
+    fn get_name() -> &'static str {
+        "Lobby"
+    }
+
+    fn input_pattern() -> Option<&'static str> {
+        Some(r"^\d+$")
+    }
+
+    fn example_input() -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+}
+
+fn parse_bank(line: &str) -> Result<String> {
+    if !line.chars().all(|c| c.is_ascii_digit()) {
+        bail!("Expected a bank of batteries with joltages from 0 to 9");
+    }
+
+    Ok(line.to_string())
+}
+
+fn find_max_joltage(batteries: &str, digits: usize) -> Result<u64> {
+    if batteries.len() < digits {
+        bail!("Expected a bank of at least {digits} batteries");
+    }
+
+    let mut total_joltage = 0;
+    let mut start_idx = 0;
+
+    for digit in (0..digits).rev() {
+        for joltage in (1..=9).rev() {
+            match batteries[start_idx..batteries.len() - digit].find(&joltage.to_string()) {
+                Some(index) => {
+                    start_idx = start_idx + index + 1;
+                    total_joltage += joltage * 10_u64.pow(digit as u32)
+                }
+                None => continue,
+            }
+            break;
+        }
+    }
+
+    Ok(total_joltage)
+}
@@ -1,10 +1,10 @@
-use std::fmt::Display;
+use anyhow::{Result, bail};
 
-use crate::days::Day;
+use crate::days::{Answer, Day, LineContext};
 
 pub struct Day04;
 
-const _EXAMPLE_INPUT: &str = "..@@.@@@@.
+const EXAMPLE_INPUT: &str = "..@@.@@@@.
 @@@.@.@.@@
 @@@@@.@.@@
 @.@@@@..@.
@@ -54,13 +54,15 @@ impl Coordinate {
 }
 
 impl Day for Day04 {
-    fn part_one(input: &str) -> impl Display {
-        let grid = input
-            .lines()
-            .map(|line| line.chars().collect::<Vec<char>>())
-            .collect::<Vec<Vec<char>>>();
+    type Parsed = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>> {
+        parse_grid(input)
+    }
 
-        grid.iter()
+    fn part_one(grid: &Vec<Vec<char>>) -> Result<impl Into<Answer>> {
+        Ok(grid
+            .iter()
             .enumerate()
             .map(|(y, line)| {
                 line.iter()
@@ -84,53 +86,99 @@ impl Day for Day04 {
                     .filter(|&neighboring_rolls| neighboring_rolls < 4)
                     .count()
             })
-            .sum::<usize>()
+            .sum::<usize>())
     }
 
-    fn part_two(input: &str) -> impl Display {
-        let mut removable_count = 0;
+    fn part_two(grid: &Vec<Vec<char>>) -> Result<impl Into<Answer>> {
+        Ok(removal_waves(grid).iter().sum::<usize>())
+    }
 
-        let mut grid = input
-            .lines()
-            .map(|line| line.chars().collect::<Vec<char>>())
-            .collect::<Vec<Vec<char>>>();
+    fn get_day_num() -> u8 {
+        return 4;
+    }
 
-        let mut removable = find_removable(&grid);
+    fn get_name() -> &'static str {
+        "Printing Department"
+    }
 
-        while removable.len() != 0 {
-            removable_count += removable.len();
+    fn input_pattern() -> Option<&'static str> {
+        Some(r"^[.@]+$")
+    }
 
-            grid = grid
-                .iter()
-                .enumerate()
-                .map(|(y, line)| {
-                    line.iter()
-                        .enumerate()
-                        .map(|(x, element)| match (x, y) {
-                            (x, y)
-                                if removable.contains(&Coordinate {
-                                    x: x as isize,
-                                    y: y as isize,
-                                }) =>
-                            {
-                                '.'
-                            }
+    fn example_input() -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+}
 
-                            _ => *element,
-                        })
-                        .collect::<Vec<char>>()
-                })
-                .collect::<Vec<Vec<char>>>();
+// part one's rolls are the ones removed in part two's first wave
+pub fn solve_both(grid: &[Vec<char>]) -> Result<(usize, usize)> {
+    let waves = removal_waves(grid);
 
-            removable = find_removable(&grid);
-        }
+    Ok((
+        waves.first().copied().unwrap_or(0),
+        waves.iter().sum::<usize>(),
+    ))
+}
+
+// removes every roll that can be removed until none are left, returning how many were removed in each wave
+fn removal_waves(grid: &[Vec<char>]) -> Vec<usize> {
+    let mut waves = vec![];
+
+    let mut grid = grid.to_vec();
+
+    let mut removable = find_removable(&grid);
+
+    while removable.len() != 0 {
+        waves.push(removable.len());
+
+        grid = grid
+            .iter()
+            .enumerate()
+            .map(|(y, line)| {
+                line.iter()
+                    .enumerate()
+                    .map(|(x, element)| match (x, y) {
+                        (x, y)
+                            if removable.contains(&Coordinate {
+                                x: x as isize,
+                                y: y as isize,
+                            }) =>
+                        {
+                            '.'
+                        }
+
+                        _ => *element,
+                    })
+                    .collect::<Vec<char>>()
+            })
+            .collect::<Vec<Vec<char>>>();
 
-        removable_count
+        removable = find_removable(&grid);
     }
 
-    fn get_day_num() -> u8 {
-        return 4;
+    waves
+}
+
+fn parse_grid(input: &str) -> Result<Vec<Vec<char>>> {
+    let width = input.lines().next().map_or(0, |line| line.chars().count());
+
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_num, line)| parse_row(line, width).line_context(line_num, line))
+        .collect()
+}
+
+fn parse_row(line: &str, width: usize) -> Result<Vec<char>> {
+    let row = line.chars().collect::<Vec<char>>();
+    if row.is_empty() || row.len() != width {
+        bail!("Expected a row of {width} tiles, found {}", row.len());
     }
+    if let Some(tile) = row.iter().find(|&&tile| tile != '@' && tile != '.') {
+        bail!("Unexpected tile {tile:?}");
+    }
+
+    Ok(row)
 }
 
 fn find_removable(grid: &Vec<Vec<char>>) -> Vec<Coordinate> {
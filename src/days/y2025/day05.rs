@@ -0,0 +1,133 @@
+use anyhow::{Context, Result, bail};
+use itertools::Itertools;
+use std::ops::Range;
+
+use crate::days::{Answer, Day, LineContext};
+
+const EXAMPLE_INPUT: &str = "3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+
+pub struct Day05;
+
+pub struct Inventory {
+    ranges: Vec<Range<i64>>,
+    ingredients: Vec<i64>,
+}
+
+impl Day for Day05 {
+    type Parsed = Inventory;
+
+    fn parse(input: &str) -> Result<Inventory> {
+        let (range_str, ingredient_str) = split_input(input)?;
+
+        Ok(Inventory {
+            ranges: parse_ranges(range_str)?,
+            // ingredient IDs start after the ranges and the blank line
+            ingredients: parse_ingredients(ingredient_str, range_str.lines().count() + 1)?,
+        })
+    }
+
+    fn part_one(inventory: &Inventory) -> Result<impl Into<Answer>> {
+        Ok(inventory
+            .ingredients
+            .iter()
+            .filter_map(
+                |ingredient| match inventory.ranges.iter().any(|range| range.contains(ingredient)) {
+                    true => Some(ingredient),
+                    _ => None,
+                },
+            )
+            .count())
+    }
+
+    fn part_two(inventory: &Inventory) -> Result<impl Into<Answer>> {
+        let ranges: Vec<Range<i64>> = inventory
+            .ranges
+            .iter()
+            .map(|range| (range.start, range.end))
+            .sorted() // sweep line algorithm requires list of ranges sorted by start of range
+            .map(|(start, end)| start..end)
+            .collect();
+
+        if ranges.is_empty() {
+            bail!("No fresh ingredient ID ranges");
+        }
+
+        // sweep line algorithm for finding union of intervals
+        let mut union_of_intervals = vec![];
+        union_of_intervals.push(ranges[0].clone());
+
+        for range in ranges {
+            if union_of_intervals.last().unwrap().end < range.start {
+                union_of_intervals.push(range);
+            } else if union_of_intervals.last().unwrap().end < range.end {
+                union_of_intervals.last_mut().unwrap().end = range.end;
+            }
+        }
+
+        Ok(union_of_intervals
+            .iter()
+            .map(|range| range.end - range.start)
+            .sum::<i64>())
+    }
+
+    fn get_day_num() -> u8 {
+        return 5;
+    }
+
+    fn get_name() -> &'static str {
+        "Cafeteria"
+    }
+
+    fn input_pattern() -> Option<&'static str> {
+        Some(r"^(\d+-\d+|\d+)?$")
+    }
+
+    fn example_input() -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+}
+
+fn split_input(input: &str) -> Result<(&str, &str)> {
+    input
+        .split_once("\n\n")
+        .context("Missing blank line between ID ranges and ingredient IDs")
+}
+
+fn parse_ranges(ranges: &str) -> Result<Vec<Range<i64>>> {
+    ranges
+        .lines()
+        .enumerate()
+        .map(|(line_num, line)| parse_range(line).line_context(line_num, line))
+        .collect()
+}
+
+fn parse_range(line: &str) -> Result<Range<i64>> {
+    let (from, to) = line
+        .trim()
+        .split_once("-")
+        .context("Missing '-' in range")?;
+    Ok(from.parse()?..(to.parse::<i64>()? + 1))
+}
+
+// first_line_num is the index of the first ingredient's line in the whole input
+fn parse_ingredients(ingredients: &str, first_line_num: usize) -> Result<Vec<i64>> {
+    ingredients
+        .lines()
+        .enumerate()
+        .map(|(line_num, line)| {
+            line.trim()
+                .parse()
+                .line_context(first_line_num + line_num, line)
+        })
+        .collect()
+}
@@ -0,0 +1,141 @@
+use anyhow::{Context, Result, bail};
+use std::vec;
+
+use crate::days::{Answer, Day, LineContext};
+
+const EXAMPLE_INPUT: &str = "123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  
+";
+
+pub struct Day06;
+
+// the worksheet read both ways, since the numbers are in rows for part one and in columns for part two
+pub struct Worksheet {
+    rows: (Vec<Vec<u64>>, Vec<String>),
+    columns: (Vec<Vec<u64>>, Vec<char>),
+}
+
+impl Day for Day06 {
+    type Parsed = Worksheet;
+
+    fn parse(input: &str) -> Result<Worksheet> {
+        Ok(Worksheet {
+            rows: parse_input(input)?,
+            columns: parse_input_part_two(input)?,
+        })
+    }
+
+    fn part_one(worksheet: &Worksheet) -> Result<impl Into<Answer>> {
+        let (args, operations) = &worksheet.rows;
+
+        operations
+            .iter()
+            .enumerate()
+            .map(|(index, operation)| -> Result<u64> {
+                match operation.as_str() {
+                    "+" => Ok(args.iter().map(|arg_vec| arg_vec[index]).sum()),
+                    "*" => Ok(args.iter().map(|arg_vec| arg_vec[index]).product()),
+                    other => bail!("Unexpected operation {other:?}"),
+                }
+            })
+            .sum::<Result<u64>>()
+    }
+
+    fn part_two(worksheet: &Worksheet) -> Result<impl Into<Answer>> {
+        let (args, operations) = &worksheet.columns;
+
+        args.iter()
+            .enumerate()
+            .map(|(index, op_args)| -> Result<u64> {
+                match operations.get(index) {
+                    Some('+') => Ok(op_args.iter().sum()),
+                    Some('*') => Ok(op_args.iter().product()),
+                    Some(other) => bail!("Unexpected operation {other:?}"),
+                    None => bail!("Missing operation for problem {}", index + 1),
+                }
+            })
+            .sum::<Result<u64>>()
+    }
+
+    fn get_day_num() -> u8 {
+        return 6;
+    }
+
+    fn get_name() -> &'static str {
+        "Trash Compactor"
+    }
+
+    fn input_pattern() -> Option<&'static str> {
+        Some(r"^[\d *+]+$")
+    }
+
+    fn example_input() -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Vec<u64>>, Vec<String>)> {
+    let lines: Vec<&str> = input.lines().collect();
+    let (operation_line, arg_lines) = lines.split_last().context("Input is empty")?;
+    let operations: Vec<String> = operation_line
+        .split_whitespace()
+        .map(String::from)
+        .collect();
+    let args = arg_lines
+        .iter()
+        .enumerate()
+        .map(|(line_num, line)| parse_args(line, operations.len()).line_context(line_num, line))
+        .collect::<Result<Vec<Vec<u64>>>>()?;
+
+    Ok((args, operations))
+}
+
+fn parse_args(line: &str, arg_count: usize) -> Result<Vec<u64>> {
+    let args = line
+        .split_whitespace()
+        .map(|arg| arg.parse())
+        .collect::<Result<Vec<u64>, _>>()?;
+    if args.len() != arg_count {
+        bail!("Expected {arg_count} numbers, found {}", args.len());
+    }
+
+    Ok(args)
+}
+
+fn parse_input_part_two(input: &str) -> Result<(Vec<Vec<u64>>, Vec<char>)> {
+    let input_grid: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    let (operation_row, argument_input) = input_grid.split_last().context("Input is empty")?;
+    // lines may have lost their trailing spaces, treat missing characters as spaces
+    let width = input_grid.iter().map(|row| row.len()).max().unwrap_or(0);
+    let mut args = vec![];
+    let mut operations = vec![];
+
+    let mut operation_args = vec![];
+    for column in 0..width {
+        let operation = operation_row.get(column).copied().unwrap_or(' ');
+
+        if operation != ' ' {
+            operations.push(operation);
+            if !operation_args.is_empty() {
+                args.push(operation_args);
+            }
+            operation_args = vec![];
+        }
+
+        let arg = argument_input
+            .iter()
+            .map(|row| row.get(column).copied().unwrap_or(' '))
+            .collect::<String>()
+            .trim()
+            .parse::<u64>();
+
+        match arg {
+            Ok(arg) => operation_args.push(arg),
+            _ => (),
+        }
+    }
+    args.push(operation_args);
+    Ok((args, operations))
+}
@@ -1,14 +1,14 @@
+use anyhow::{Context, Result};
 use std::{
     collections::{HashMap, HashSet},
-    fmt::Display,
     hash::Hash,
     iter::repeat,
     vec,
 };
 
-use crate::days::Day;
+use crate::days::{Answer, Day};
 
-const _EXAMPLE_INPUT: &str = ".......S.......
+const EXAMPLE_INPUT: &str = ".......S.......
 ...............
 .......^.......
 ...............
@@ -34,14 +34,34 @@ struct Node {
 
 pub struct Day07;
 
+pub struct Manifold {
+    grid: Vec<Vec<char>>,
+    start_column: usize,
+    // the splitters, then the last row's tiles as leaves
+    nodes: Vec<Node>,
+}
+
 impl Day for Day07 {
-    fn part_one(input: &str) -> impl Display {
+    type Parsed = Manifold;
+
+    fn parse(input: &str) -> Result<Manifold> {
+        Ok(Manifold {
+            grid: input.lines().map(|line| line.chars().collect()).collect(),
+            start_column: input
+                .lines()
+                .find_map(|line| line.find('S'))
+                .context("No beam start 'S' in the manifold")?,
+            nodes: parse_input(input)?,
+        })
+    }
+
+    fn part_one(manifold: &Manifold) -> Result<impl Into<Answer>> {
         let mut beam_columns: HashSet<usize> = HashSet::new();
-        beam_columns.insert(input.find('S').unwrap());
+        beam_columns.insert(manifold.start_column);
         let mut splits = 0;
 
-        input.lines().for_each(|line| {
-            line.chars().enumerate().for_each(|(idx, c)| {
+        manifold.grid.iter().for_each(|line| {
+            line.iter().enumerate().for_each(|(idx, &c)| {
                 if c == '^' && beam_columns.contains(&idx) {
                     splits += 1;
                     beam_columns.remove(&idx);
@@ -51,11 +71,11 @@ impl Day for Day07 {
             })
         });
 
-        splits
+        Ok(splits)
     }
 
-    fn part_two(input: &str) -> impl Display {
-        let nodes = parse_input(input);
+    fn part_two(manifold: &Manifold) -> Result<impl Into<Answer>> {
+        let nodes = &manifold.nodes;
         let node_count = nodes.len();
         let mut paths: Vec<Vec<u64>> = vec![vec![0; node_count]; node_count];
 
@@ -95,7 +115,7 @@ impl Day for Day07 {
             }
         }
 
-        paths[0]
+        Ok(paths[0]
             .iter()
             .enumerate()
             .filter_map(|(index, path_count)| {
@@ -105,17 +125,51 @@ impl Day for Day07 {
                     None
                 }
             })
-            .sum::<u64>()
+            .sum::<u64>())
     }
 
     fn get_day_num() -> u8 {
         return 7;
     }
+
+    fn get_name() -> &'static str {
+        "Laboratories"
+    }
+
+    fn input_pattern() -> Option<&'static str> {
+        Some(r"^[.S^]+$")
+    }
+
+    fn example_input() -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
 }
 
-fn parse_input(input: &str) -> Vec<Node> {
-    let last_row = input.lines().count() - 1;
-    input
+// sweeping the beams down like part one, but counting how many timelines each beam is in,
+// gives part two's answer as the number of timelines that reach the bottom
+pub fn solve_both(manifold: &Manifold) -> Result<(usize, u64)> {
+    let mut timelines: HashMap<usize, u64> = HashMap::new();
+    timelines.insert(manifold.start_column, 1);
+    let mut splits = 0;
+
+    manifold.grid.iter().for_each(|line| {
+        line.iter().enumerate().for_each(|(idx, &c)| {
+            if c == '^'
+                && let Some(count) = timelines.remove(&idx)
+            {
+                splits += 1;
+                *timelines.entry(idx + 1).or_insert(0) += count;
+                *timelines.entry(idx - 1).or_insert(0) += count;
+            }
+        })
+    });
+
+    Ok((splits, timelines.values().sum::<u64>()))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Node>> {
+    let (last_row, last_line) = input.lines().enumerate().last().context("Input is empty")?;
+    let nodes = input
         .lines()
         .enumerate()
         .map(|(row, line)| {
@@ -131,20 +185,14 @@ fn parse_input(input: &str) -> Vec<Node> {
                 })
         })
         .flatten()
-        .chain(
-            input
-                .lines()
-                .last()
-                .unwrap()
-                .chars()
-                .enumerate()
-                .map(|(column, _)| Node {
-                    row: last_row,
-                    column,
-                    leaf: true,
-                }),
-        )
-        .collect()
+        .chain(last_line.chars().enumerate().map(|(column, _)| Node {
+            row: last_row,
+            column,
+            leaf: true,
+        }))
+        .collect();
+
+    Ok(nodes)
 }
 
 fn _print_path_matrix(paths: &Vec<Vec<u32>>, nodes: &Vec<Node>) {
@@ -0,0 +1,251 @@
+use std::cmp::Ordering;
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::days::{Answer, Day, LineContext};
+use anyhow::{Context, Result, bail};
+use itertools::Itertools;
+
+pub struct Day08;
+
+const EXAMPLE_INPUT: &str = "162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689";
+
+const CONNECTIONS_TO_MAKE: usize = 1000;
+// the example makes fewer connections
+const EXAMPLE_CONNECTIONS_TO_MAKE: usize = 10;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct JunctionBox {
+    x: isize,
+    y: isize,
+    z: isize,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Edge {
+    from: JunctionBox,
+    to: JunctionBox,
+    len: f64,
+}
+
+impl PartialEq for Edge {
+    fn eq(&self, other: &Self) -> bool {
+        (self.from == other.from && self.to == other.to)
+            || (self.from == other.to && self.to == other.from)
+    }
+}
+
+impl Eq for Edge {}
+
+impl Ord for Edge {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.len.total_cmp(&other.len)
+    }
+}
+
+impl PartialOrd for Edge {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Edge {
+    fn new(from: JunctionBox, to: JunctionBox) -> Edge {
+        Edge {
+            from,
+            to,
+            len: f64::sqrt(
+                ((from.x - to.x).pow(2) + (from.y - to.y).pow(2) + (from.z - to.z).pow(2)) as f64,
+            ),
+        }
+    }
+}
+
+pub struct Playground {
+    // every junction box in its own circuit
+    circuits: Vec<HashSet<JunctionBox>>,
+    // every edge between two boxes, sorted by length
+    edges: VecDeque<Edge>,
+    // how many of the shortest edges part one connects
+    connections_to_make: usize,
+}
+
+impl Day for Day08 {
+    type Parsed = Playground;
+
+    fn parse(input: &str) -> Result<Playground> {
+        parse_input(input, CONNECTIONS_TO_MAKE)
+    }
+
+    fn parse_example(input: &str) -> Result<Playground> {
+        parse_input(input, EXAMPLE_CONNECTIONS_TO_MAKE)
+    }
+
+    fn part_one(playground: &Playground) -> Result<impl Into<Answer>> {
+        let mut circuits = playground.circuits.clone();
+
+        // Kruskal's algorithm, but cut off at 1000 edges checked
+        for &edge in playground
+            .edges
+            .iter()
+            .take(playground.connections_to_make)
+        {
+            connect(&mut circuits, edge);
+        }
+
+        Ok(largest_circuits_product(&circuits))
+    }
+
+    fn part_two(playground: &Playground) -> Result<impl Into<Answer>> {
+        let mut circuits = playground.circuits.clone();
+
+        // Kruskal's algorithm properly this time
+        let mut last_edge = *playground
+            .edges
+            .front()
+            .context("At least two junction boxes are needed to connect anything")?;
+        for &edge in &playground.edges {
+            if connect(&mut circuits, edge) {
+                last_edge = edge;
+            }
+        }
+
+        Ok(last_edge.from.x * last_edge.to.x)
+    }
+
+    fn get_day_num() -> u8 {
+        return 8;
+    }
+
+    fn get_name() -> &'static str {
+        "Playground"
+    }
+
+    fn input_pattern() -> Option<&'static str> {
+        Some(r"^\d+,\d+,\d+$")
+    }
+
+    fn example_input() -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+}
+
+fn parse_input(input: &str, connections_to_make: usize) -> Result<Playground> {
+    let boxes: Vec<JunctionBox> = input
+        .lines()
+        .enumerate()
+        .map(|(line_num, line)| parse_junction_box(line).line_context(line_num, line))
+        .collect::<Result<Vec<JunctionBox>>>()?;
+
+    let circuits: Vec<HashSet<JunctionBox>> = boxes
+        .iter()
+        .map(|junction_box| {
+            let mut circuit = HashSet::new();
+            circuit.insert(junction_box.clone());
+            circuit
+        })
+        .collect();
+
+    let edges: VecDeque<Edge> = boxes
+        .iter()
+        .combinations(2)
+        .map(|edge| Edge::new(*edge[0], *edge[1]))
+        .sorted()
+        .collect();
+
+    Ok(Playground {
+        circuits,
+        edges,
+        connections_to_make,
+    })
+}
+
+fn parse_junction_box(line: &str) -> Result<JunctionBox> {
+    let coords = line
+        .split(",")
+        .map(|coord_str| coord_str.trim().parse())
+        .collect::<Result<Vec<isize>, _>>()?;
+
+    match coords[..] {
+        [x, y, z] => Ok(JunctionBox { x, y, z }),
+        _ => bail!("Expected 3 coordinates, found {}", coords.len()),
+    }
+}
+
+// merges the circuits of the edge's junction boxes, returns false if they were in the same circuit already
+fn connect(circuits: &mut Vec<HashSet<JunctionBox>>, edge: Edge) -> bool {
+    let from_idx = find_circuit_index(circuits, edge.from);
+    let to_idx = find_circuit_index(circuits, edge.to);
+
+    if from_idx == to_idx {
+        return false;
+    }
+    circuits[from_idx] = circuits[from_idx]
+        .union(&circuits[to_idx])
+        .map(|junction_box| *junction_box)
+        .collect();
+    circuits.remove(to_idx);
+
+    true
+}
+
+fn largest_circuits_product(circuits: &[HashSet<JunctionBox>]) -> usize {
+    circuits
+        .iter()
+        .map(|circuit| circuit.len())
+        .sorted()
+        .rev()
+        .take(3)
+        .product()
+}
+
+// part one's connections are the first ones part two makes, so one run of Kruskal's algorithm finds both
+pub fn solve_both(playground: &Playground) -> Result<(usize, isize)> {
+    let mut circuits = playground.circuits.clone();
+    let mut largest_product = None;
+
+    let mut last_edge = *playground
+        .edges
+        .front()
+        .context("At least two junction boxes are needed to connect anything")?;
+    for (index, &edge) in playground.edges.iter().enumerate() {
+        if index == playground.connections_to_make {
+            largest_product = Some(largest_circuits_product(&circuits));
+        }
+        if connect(&mut circuits, edge) {
+            last_edge = edge;
+        }
+    }
+
+    Ok((
+        // there may be fewer edges than connections to make
+        largest_product.unwrap_or_else(|| largest_circuits_product(&circuits)),
+        last_edge.from.x * last_edge.to.x,
+    ))
+}
+
+fn find_circuit_index(circuits: &Vec<HashSet<JunctionBox>>, to_find: JunctionBox) -> usize {
+    circuits
+        .iter()
+        .position(|circuit| circuit.contains(&to_find))
+        .unwrap()
+}
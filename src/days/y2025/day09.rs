@@ -1,12 +1,12 @@
+use anyhow::{Context, Result, bail};
 use itertools::Itertools;
 use std::cmp::{max, min};
-use std::fmt::Display;
 
-use crate::days::Day;
+use crate::days::{Answer, Day, LineContext};
 
 pub struct Day09;
 
-const _EXAMPLE_INPUT: &str = "7,1
+const EXAMPLE_INPUT: &str = "7,1
 11,1
 11,7
 9,7
@@ -16,7 +16,7 @@ const _EXAMPLE_INPUT: &str = "7,1
 7,3";
 
 #[derive(Clone, Copy, Debug)]
-struct Tile {
+pub struct Tile {
     x: isize,
     y: isize,
 }
@@ -132,19 +132,23 @@ impl Rectangle {
 }
 
 impl Day for Day09 {
-    fn part_one(input: &str) -> impl Display {
-        let tiles = parse_input(input);
+    type Parsed = Vec<Tile>;
 
+    fn parse(input: &str) -> Result<Vec<Tile>> {
+        parse_input(input)
+    }
+
+    fn part_one(tiles: &Vec<Tile>) -> Result<impl Into<Answer>> {
         tiles
             .iter()
             .combinations(2)
             .map(|corners| rectangle_area(corners[0], corners[1]))
             .max()
-            .unwrap()
+            .context("At least two red tiles are needed for a rectangle")
     }
 
-    fn part_two(input: &str) -> impl Display {
-        let (tiles, edges) = parse_input_part_two(input);
+    fn part_two(tiles: &Vec<Tile>) -> Result<impl Into<Answer>> {
+        let edges = polygon_edges(tiles)?;
 
         tiles
             .iter()
@@ -153,30 +157,52 @@ impl Day for Day09 {
             .sorted_by_key(|rectangle| rectangle.size)
             .rev()
             .find(|rectangle| rectangle.is_inside_polygon(&edges))
-            .unwrap()
-            .size
+            .map(|rectangle| rectangle.size)
+            .context("No rectangle fits inside the red and green tiles")
     }
 
     fn get_day_num() -> u8 {
         return 9;
     }
+
+    fn get_name() -> &'static str {
+        "Movie Theater"
+    }
+
+    fn input_pattern() -> Option<&'static str> {
+        Some(r"^\d+,\d+$")
+    }
+
+    fn example_input() -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
 }
 
-fn parse_input(input: &str) -> Vec<Tile> {
+fn parse_input(input: &str) -> Result<Vec<Tile>> {
     input
         .lines()
-        .map(|line| {
-            let mut coords = line.split(",").map(|coord_str| coord_str.parse().unwrap());
-            Tile {
-                x: coords.next().unwrap(),
-                y: coords.next().unwrap(),
-            }
-        })
+        .enumerate()
+        .map(|(line_num, line)| parse_tile(line).line_context(line_num, line))
         .collect()
 }
 
-fn parse_input_part_two(input: &str) -> (Vec<Tile>, Vec<Edge>) {
-    let tiles = parse_input(input);
+fn parse_tile(line: &str) -> Result<Tile> {
+    let coords = line
+        .split(",")
+        .map(|coord_str| coord_str.trim().parse())
+        .collect::<Result<Vec<isize>, _>>()?;
+
+    match coords[..] {
+        [x, y] => Ok(Tile { x, y }),
+        _ => bail!("Expected 2 coordinates, found {}", coords.len()),
+    }
+}
+
+fn polygon_edges(tiles: &[Tile]) -> Result<Vec<Edge>> {
+    if tiles.len() < 2 {
+        bail!("At least two red tiles are needed for a rectangle");
+    }
+
     let mut edges: Vec<Edge> = tiles
         .iter()
         .tuple_windows()
@@ -186,7 +212,7 @@ fn parse_input_part_two(input: &str) -> (Vec<Tile>, Vec<Edge>) {
     // list wraps around, must add edge from last tile to first tile
     edges.push(Edge::new(tiles[tiles.len() - 1], tiles[0]));
 
-    (tiles, edges)
+    Ok(edges)
 }
 
 fn rectangle_area(tile_a: &Tile, tile_b: &Tile) -> isize {
@@ -0,0 +1,207 @@
+use anyhow::{Context, Result, bail};
+use good_lp::solvers::coin_cbc::coin_cbc as default_solver;
+use good_lp::*;
+use itertools::Itertools;
+use regex::Regex;
+use std::vec;
+
+use crate::days::{Answer, Day, LineContext};
+
+const EXAMPLE_INPUT: &str = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+
+pub struct Machine {
+    // the input line, for errors
+    line: String,
+    indicators: Vec<bool>,
+    buttons: Vec<Button>,
+    joltages: Vec<u32>,
+}
+
+struct Button {
+    toggles: Vec<bool>,
+}
+
+pub struct Day10;
+
+impl Day for Day10 {
+    type Parsed = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Vec<Machine>> {
+        parse_input(input)
+    }
+
+    fn part_one(machines: &Vec<Machine>) -> Result<impl Into<Answer>> {
+        machines
+            .iter()
+            .map(|machine| presses_for_indicator(machine))
+            .sum::<Result<usize>>()
+    }
+
+    fn part_two(machines: &Vec<Machine>) -> Result<impl Into<Answer>> {
+        machines
+            .iter()
+            .map(|machine| presses_for_joltage(&machine))
+            .sum::<Result<usize>>()
+    }
+
+    fn get_day_num() -> u8 {
+        return 10;
+    }
+
+    fn get_name() -> &'static str {
+        "Factory"
+    }
+
+    fn input_pattern() -> Option<&'static str> {
+        Some(r"^\[[.#]+\]( \(\d+(,\d+)*\))+ \{\d+(,\d+)*\}$")
+    }
+
+    fn example_input() -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+}
+
+fn presses_for_indicator(machine: &Machine) -> Result<usize> {
+    let mut presses = 1;
+
+    loop {
+        if machine
+            .buttons
+            .iter()
+            .permutations(presses)
+            .any(|button_combo| {
+                button_combo.iter().fold(
+                    vec![false; button_combo[0].toggles.len()],
+                    |acc, button| {
+                        acc.iter()
+                            .enumerate()
+                            .map(|(i, indicator)| indicator ^ button.toggles[i])
+                            .collect()
+                    },
+                ) == machine.indicators
+            })
+        {
+            break;
+        }
+
+        presses += 1;
+
+        if presses == 10 {
+            bail!(
+                "The indicators of {:?} can't be reached in fewer than 10 presses",
+                machine.line
+            );
+        }
+    }
+
+    Ok(presses)
+}
+
+fn presses_for_joltage(machine: &Machine) -> Result<usize> {
+    let mut variables = ProblemVariables::new();
+    let vars: Vec<Variable> =
+        variables.add_vector(variable().min(0).integer(), machine.buttons.len());
+
+    let mut problem = variables
+        .minimise({
+            let mut presses = Expression::with_capacity(machine.buttons.len());
+            for var in &vars {
+                presses += var;
+            }
+
+            presses
+        })
+        .using(default_solver);
+    problem.set_parameter("loglevel", "0");
+
+    for j_index in 0..machine.joltages.len() {
+        problem = problem.with(
+            ({
+                let mut new_joltage = Expression::with_capacity(machine.joltages.len());
+                for (b_index, button) in machine.buttons.iter().enumerate() {
+                    if button.toggles[j_index] {
+                        new_joltage += vars[b_index];
+                    }
+                }
+
+                new_joltage
+            })
+            .eq(machine.joltages[j_index]),
+        );
+    }
+
+    let solution = problem
+        .solve()
+        .context("No combination of button presses reaches the joltage requirements")?;
+    Ok((0..machine.buttons.len())
+        .map(|index| solution.value(vars[index]))
+        .sum::<f64>() as usize)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Machine>> {
+    let indicator_re = Regex::new(r"\[([.#]+)\]").unwrap();
+    let button_re = Regex::new(r"\((\d(?:,\d)*)\)").unwrap();
+    let joltage_re = Regex::new(r"\{(\d+(?:,\d+)*)\}").unwrap();
+
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_num, line)| {
+            parse_machine(line, &indicator_re, &button_re, &joltage_re).line_context(line_num, line)
+        })
+        .collect()
+}
+
+fn parse_machine(
+    line: &str,
+    indicator_re: &Regex,
+    button_re: &Regex,
+    joltage_re: &Regex,
+) -> Result<Machine> {
+    let indicators: Vec<bool> = indicator_re
+        .captures(line)
+        .context("Missing indicator light diagram")?[1]
+        .chars()
+        .map(|indicator| indicator == '#')
+        .collect();
+    let indicator_count = indicators.len();
+
+    let buttons = button_re
+        .captures_iter(line)
+        .map(|capture| {
+            let mut button_toggles = vec![false; indicator_count];
+            for id_str in capture[1].split(",") {
+                let id = id_str.parse::<usize>()?;
+                if id >= indicator_count {
+                    bail!("Button toggles light {id}, but there are only {indicator_count} lights");
+                }
+                button_toggles[id] = true;
+            }
+            Ok(Button {
+                toggles: button_toggles,
+            })
+        })
+        .collect::<Result<Vec<Button>>>()?;
+
+    let joltages = joltage_re
+        .captures(line)
+        .context("Missing joltage requirements")?[1]
+        .split(",")
+        .map(|joltage_str| joltage_str.parse())
+        .collect::<Result<Vec<u32>, _>>()?;
+    if joltages.len() != indicator_count {
+        bail!(
+            "Expected {indicator_count} joltage requirements, found {}",
+            joltages.len()
+        );
+    }
+
+    Ok(Machine {
+        line: line.to_string(),
+        indicators,
+        buttons,
+        joltages,
+    })
+}
@@ -1,11 +1,11 @@
+use anyhow::{Context, Result};
 use std::{
     collections::{HashMap, VecDeque},
-    fmt::Display,
 };
 
-use crate::days::Day;
+use crate::days::{Answer, Day, LineContext};
 
-const _EXAMPLE_INPUT: &str = "aaa: you hhh
+const EXAMPLE_INPUT: &str = "aaa: you hhh
 you: bbb ccc
 bbb: ddd eee
 ccc: ddd eee fff
@@ -16,7 +16,7 @@ ggg: out
 hhh: ccc fff iii
 iii: out";
 
-const _EXAMPLE_INPUT_PART_TWO: &str = "svr: aaa bbb
+const EXAMPLE_INPUT_PART_TWO: &str = "svr: aaa bbb
 aaa: fft
 fft: ccc
 bbb: tty
@@ -33,49 +33,74 @@ hhh: out";
 pub struct Day11;
 
 impl Day for Day11 {
-    fn part_one(input: &str) -> impl Display {
-        let connections = parse_input(input);
+    type Parsed = HashMap<String, Vec<String>>;
 
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_input(input)
+    }
+
+    fn part_one(connections: &Self::Parsed) -> Result<impl Into<Answer>> {
         // assumption: paths from you to out all have no cycles
         // otherwise the number of paths would be infinite, which is not a possible AoC answer
-        paths("you", "out", &connections)
+        Ok(paths("you", "out", connections))
     }
 
-    fn part_two(input: &str) -> impl Display {
-        let connctions = parse_input(input);
-
+    fn part_two(connctions: &Self::Parsed) -> Result<impl Into<Answer>> {
         // searching svr->[dac|fft], [dac->fft|fft->dac], [dac|fft]->out and multiplying
         // assumption: paths between the individual stops all have no cycles, and one of dac->fft and fft->dac is 0
         // otherwise the number of valid paths would be infinite, which is not a possible AoC answer
 
-        let paths_dac_fft = paths("dac", "fft", &connctions);
+        let paths_dac_fft = paths("dac", "fft", connctions);
 
-        if paths_dac_fft == 0 {
+        Ok(if paths_dac_fft == 0 {
             // paths from svr to fft, then to dac, then out
-            let paths_svr_fft = paths("svr", "fft", &connctions);
-            let paths_fft_dac = paths("fft", "dac", &connctions);
-            let paths_dac_out = paths("dac", "out", &connctions);
+            let paths_svr_fft = paths("svr", "fft", connctions);
+            let paths_fft_dac = paths("fft", "dac", connctions);
+            let paths_dac_out = paths("dac", "out", connctions);
             paths_svr_fft * paths_fft_dac * paths_dac_out
         } else {
             // paths from svr to dac, then to fft, then out
-            let paths_svr_dac = paths("svr", "dac", &connctions);
-            let paths_fft_out = paths("fft", "out", &connctions);
+            let paths_svr_dac = paths("svr", "dac", connctions);
+            let paths_fft_out = paths("fft", "out", connctions);
             paths_svr_dac * paths_dac_fft * paths_fft_out
-        }
+        })
     }
 
     fn get_day_num() -> u8 {
         return 11;
     }
+
+    fn get_name() -> &'static str {
+        "Reactor"
+    }
+
+    fn input_pattern() -> Option<&'static str> {
+        Some(r"^[a-z]{3}:( [a-z]{3})+$")
+    }
+
+    fn example_input() -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+
+    fn example_input_part_two() -> Option<&'static str> {
+        Some(EXAMPLE_INPUT_PART_TWO)
+    }
 }
 
 // returns Hashmap node -> nodes it connects to
-fn parse_input(input: &str) -> HashMap<&str, Vec<&str>> {
+fn parse_input(input: &str) -> Result<HashMap<String, Vec<String>>> {
     input
         .lines()
-        .map(|line| {
-            let (from, to_str) = line.split_once(": ").unwrap();
-            (from, to_str.split_whitespace().collect())
+        .enumerate()
+        .map(|(line_num, line)| {
+            let (from, to_str) = line
+                .split_once(": ")
+                .context("Missing ': ' after device name")
+                .line_context(line_num, line)?;
+            Ok((
+                from.to_string(),
+                to_str.split_whitespace().map(String::from).collect(),
+            ))
         })
         .collect()
 }
@@ -83,7 +108,7 @@ fn parse_input(input: &str) -> HashMap<&str, Vec<&str>> {
 // contains no cycle detection (only counts number of paths, does not record their nodes, so no way to tell)
 // -> will not terminate if any paths with cycles lead to node <to>, even if not from node <from>
 // my input seems to contain none (even outside the relevant paths), possibly true for all existing inputs?
-fn paths(from: &str, to: &str, connections: &HashMap<&str, Vec<&str>>) -> u64 {
+fn paths(from: &str, to: &str, connections: &HashMap<String, Vec<String>>) -> u64 {
     let mut path_count_to: HashMap<&str, u64> = HashMap::new();
     path_count_to.insert(from, 1);
 
@@ -99,13 +124,13 @@ fn paths(from: &str, to: &str, connections: &HashMap<&str, Vec<&str>>) -> u64 {
 
         connections
             .get(current_node)
-            .unwrap_or(&vec![])
-            .iter()
+            .into_iter()
+            .flatten()
             .for_each(|next_node| {
-                *path_count_to.entry(&next_node).or_insert(0) +=
+                *path_count_to.entry(next_node).or_insert(0) +=
                     *path_count_to.get(current_node).unwrap_or(&0);
                 if *next_node != to {
-                    to_check.push_back(&next_node);
+                    to_check.push_back(next_node);
                 }
             });
 
@@ -1,7 +1,7 @@
-use regex::Regex;
-use std::fmt::Display;
+use anyhow::{Context, Result};
+use regex::{Captures, Regex};
 
-use crate::days::Day;
+use crate::days::{Answer, Day};
 
 pub struct Day12;
 
@@ -9,21 +9,25 @@ pub struct Day12;
 const PRESENT_SIZE_X: usize = 3;
 const PRESENT_SIZE_Y: usize = 3;
 
-struct Space {
+pub struct Space {
     x_size: usize,
     y_size: usize,
     present_count: usize,
 }
 
 impl Day for Day12 {
+    type Parsed = Vec<Space>;
+
+    fn parse(input: &str) -> Result<Vec<Space>> {
+        parse_input(input)
+    }
+
     // tried various ways to cut down the search space for ages, plus caching, eventually ran out of ideas
     // checked the subreddit and saw a) this solution worked (for the actual input, not the example),
     // and b) "proper" solutions were either stochastic or took ages,
     // like one I saw for a proper SAT problem that took 75 CPU hours with a proper CBC solver
-    fn part_one(input: &str) -> impl Display {
-        let spaces = parse_input(input);
-
-        spaces
+    fn part_one(spaces: &Vec<Space>) -> Result<impl Into<Answer>> {
+        Ok(spaces
             .iter()
             .filter(|space| {
                 (space.present_count / (space.y_size / PRESENT_SIZE_Y)) * PRESENT_SIZE_X
@@ -31,35 +35,44 @@ impl Day for Day12 {
                     && (space.present_count / (space.x_size / PRESENT_SIZE_X)) * PRESENT_SIZE_Y
                         <= space.y_size
             })
-            .count()
+            .count())
     }
 
-    fn part_two(_input: &str) -> impl Display {
-        "No computation needed :)"
+    fn part_two(_spaces: &Vec<Space>) -> Result<impl Into<Answer>> {
+        Ok(Answer::NotApplicable)
     }
 
     fn get_day_num() -> u8 {
         return 12;
     }
+
+    fn get_name() -> &'static str {
+        "Christmas Tree Farm"
+    }
+
+    fn input_pattern() -> Option<&'static str> {
+        Some(r"^(\d+:|[#.]+|\d+x\d+:( \d+)+)?$")
+    }
 }
 
-fn parse_input(input: &str) -> Vec<Space> {
+fn parse_input(input: &str) -> Result<Vec<Space>> {
     let space_regex = Regex::new(r"(\d+)x(\d+): ((?:\d+ ?)+)").unwrap();
-    let spaces = space_regex
+    space_regex
         .captures_iter(input)
-        .map(|cap| Space {
-            x_size: cap.get(1).unwrap().as_str().parse().unwrap(),
-            y_size: cap.get(2).unwrap().as_str().parse().unwrap(),
-
-            present_count: cap
-                .get(3)
-                .unwrap()
-                .as_str()
-                .split_whitespace()
-                .map(|occurrence_str| occurrence_str.parse::<usize>().unwrap())
-                .sum(),
+        .map(|cap| {
+            parse_space(&cap).with_context(|| format!("Couldn't parse region {:?}", &cap[0]))
         })
-        .collect();
+        .collect()
+}
+
+fn parse_space(cap: &Captures) -> Result<Space> {
+    Ok(Space {
+        x_size: cap[1].parse()?,
+        y_size: cap[2].parse()?,
 
-    spaces
+        present_count: cap[3]
+            .split_whitespace()
+            .map(|occurrence_str| occurrence_str.parse::<usize>())
+            .sum::<Result<usize, _>>()?,
+    })
 }
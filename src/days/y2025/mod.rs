@@ -0,0 +1,15 @@
+register_days! {
+    2025;
+    day01::Day01,
+    day02::Day02,
+    day03::Day03,
+    day04::Day04 + solve_both,
+    day05::Day05,
+    day06::Day06,
+    day07::Day07 + solve_both,
+    day08::Day08 + solve_both,
+    day09::Day09,
+    day10::Day10,
+    day11::Day11,
+    day12::Day12,
+}
@@ -0,0 +1,312 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result, bail};
+use regex::Regex;
+
+use crate::client::AocClient;
+
+pub fn description_path(year: i32, day: u32) -> String {
+    format!("puzzles/{year}/day{:0>2}.md", day)
+}
+
+// the inner HTML of each part's <article class="day-desc">, part two's only once part one is solved
+pub fn articles(page: &str) -> Vec<&str> {
+    let article_re = Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+
+    article_re
+        .captures_iter(page)
+        .filter_map(|article| article.get(1))
+        .map(|article| article.as_str())
+        .collect()
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn escape_markdown(text: &str) -> String {
+    text.chars()
+        .flat_map(|c| match c {
+            '\\' | '*' | '_' | '`' | '[' | ']' | '<' => vec!['\\', c],
+            c => vec![c],
+        })
+        .collect()
+}
+
+/// Converts an article from a puzzle page to Markdown. Only the elements AoC uses in its descriptions are
+/// converted (headings, paragraphs, code blocks, inline code, emphasis, lists and links), other tags are dropped.
+pub fn to_markdown(article: &str, base_url: &str) -> String {
+    // Markdown can't emphasize text inside inline code, so the emphasis is moved around it
+    let code_em_re = Regex::new(r"(?s)<code><em>(.*?)</em></code>").unwrap();
+    let token_re = Regex::new(r"(?s)<(/?)([a-z0-9]+)([^>]*)>|[^<]+").unwrap();
+    let href_re = Regex::new(r#"href="([^"]*)""#).unwrap();
+
+    let article = code_em_re.replace_all(article, "<em><code>$1</code></em>");
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    // inline code is shown as is, so its text isn't escaped
+    let mut in_code = false;
+    let mut links = vec![];
+
+    for token in token_re.captures_iter(&article) {
+        let Some(tag) = token.get(2) else {
+            let text = decode_entities(&token[0]);
+            // whitespace between block elements
+            if !in_pre
+                && text.trim().is_empty()
+                && (markdown.is_empty() || markdown.ends_with('\n'))
+            {
+                continue;
+            }
+            match (in_pre, in_code) {
+                (true, _) => markdown.push_str(&text),
+                (false, true) => markdown.push_str(&text.replace('\n', " ")),
+                // line breaks in the HTML are just whitespace
+                (false, false) => markdown.push_str(&escape_markdown(&text.replace('\n', " "))),
+            }
+            continue;
+        };
+
+        let closing = !token[1].is_empty();
+        match (tag.as_str(), closing) {
+            ("h2", false) => markdown.push_str("## "),
+            ("h2", true) | ("p", true) | ("ul", true) => markdown.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => {
+                in_code = !closing;
+                markdown.push('`');
+            }
+            ("em", _) if !in_pre => markdown.push('*'),
+            ("li", false) => markdown.push_str("- "),
+            ("li", true) => markdown.push('\n'),
+            ("a", false) => {
+                let href = href_re
+                    .captures(&token[3])
+                    .map_or(String::new(), |href| decode_entities(&href[1]));
+                links.push(match href.starts_with('/') {
+                    true => format!("{base_url}{href}"),
+                    false => href,
+                });
+                markdown.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                markdown.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    // headings are "--- Day 1: Title ---" and "--- Part Two ---"
+    let heading_re = Regex::new(r"(?m)^## --- (.*) ---$").unwrap();
+    let blank_lines_re = Regex::new(r"\n{3,}").unwrap();
+    let markdown = heading_re.replace_all(markdown.trim(), "## $1");
+    blank_lines_re
+        .replace_all(&markdown, "\n\n")
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<&str>>()
+        .join("\n")
+        + "\n"
+}
+
+/// Downloads the day's puzzle description and saves it as Markdown. Part two is included once part one is solved,
+/// so fetching the description again afterwards updates the file.
+/// Returns the number of parts in the description.
+pub fn fetch_description(client: &AocClient, year: i32, day: u32) -> Result<usize> {
+    let page = client.fetch_puzzle(year, day)?;
+    save_description(&page, client.base_url(), year, day)
+}
+
+/// Fetches the description again if it was saved before, e.g. to add part two once part one is solved.
+/// Returns whether there was a saved description to update.
+pub fn update_saved_description(year: i32, day: u32) -> Result<bool> {
+    if !Path::new(&description_path(year, day)).exists() {
+        return Ok(false);
+    }
+
+    fetch_description(&AocClient::new()?, year, day)?;
+    Ok(true)
+}
+
+// saves the description on an already downloaded puzzle page, returning the number of parts in it
+pub fn save_description(page: &str, base_url: &str, year: i32, day: u32) -> Result<usize> {
+    let (markdown, parts) = page_to_markdown(page, base_url)?;
+
+    fs::create_dir_all(format!("puzzles/{year}"))
+        .context("Couldn't create the year's puzzle directory")?;
+    let path = description_path(year, day);
+    fs::write(&path, markdown).with_context(|| format!("Couldn't write {path}"))?;
+
+    Ok(parts)
+}
+
+// every part's description on a puzzle page as one Markdown document, with the number of parts in it
+fn page_to_markdown(page: &str, base_url: &str) -> Result<(String, usize)> {
+    let articles = articles(page);
+    if articles.is_empty() {
+        bail!("Couldn't find the puzzle description on the puzzle's page");
+    }
+
+    let markdown = articles
+        .iter()
+        .map(|article| to_markdown(article, base_url))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    Ok((markdown, articles.len()))
+}
+
+/// Picks the example input from part one's description: the largest code block after "For example", or the
+/// largest code block in the description if it doesn't say "For example" anywhere.
+pub fn likely_example(page: &str) -> Option<String> {
+    let code_block_re = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let tag_re = Regex::new(r"<[^>]*>").unwrap();
+
+    let part_one = *articles(page).first()?;
+    let blocks: Vec<(usize, String)> = code_block_re
+        .captures_iter(part_one)
+        .map(|block| {
+            let code = block.get(1).unwrap();
+            (
+                code.start(),
+                decode_entities(&tag_re.replace_all(code.as_str(), "")),
+            )
+        })
+        .collect();
+
+    let for_example = part_one.find("For example").unwrap_or(0);
+    let largest = |after: usize| {
+        blocks
+            .iter()
+            .filter(|(start, _)| *start > after)
+            .max_by_key(|(_, block)| block.len())
+    };
+
+    largest(for_example)
+        .or_else(|| largest(0))
+        .map(|(_, block)| block.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_ONE_PAGE: &str = include_str!("../tests/fixtures/puzzle_part_one.html");
+    const BOTH_PARTS_PAGE: &str = include_str!("../tests/fixtures/puzzle_both_parts.html");
+    const BASE_URL: &str = "https://adventofcode.com";
+
+    #[test]
+    fn part_one_page_to_markdown() {
+        let (markdown, parts) = page_to_markdown(PART_ONE_PAGE, BASE_URL).unwrap();
+
+        assert_eq!(parts, 1);
+        assert_eq!(
+            markdown,
+            "## Day 1: Secret Entrance
+
+The Elves have good news and bad news.
+
+The safe has a dial with only an arrow on it; around the dial are the numbers `0` through `99` in order.
+
+For example, suppose the attached document contained the following rotations:
+
+```
+L68
+L30
+R48
+```
+
+Following these rotations would cause the dial to move as follows:
+
+- The dial starts by pointing at `50`.
+- The dial is rotated `L68` to point at `82`.
+
+Because the dial points at `0` a total of three times during this process, the password in this example is *`3`*.
+
+Analyze the rotations in your attached document. *What's the actual password to open the door?*
+"
+        );
+    }
+
+    #[test]
+    fn both_parts_page_to_markdown() {
+        let (markdown, parts) = page_to_markdown(BOTH_PARTS_PAGE, BASE_URL).unwrap();
+
+        assert_eq!(parts, 2);
+        assert_eq!(
+            markdown,
+            r#"## Day 7: Laboratories
+
+You thank the cephalopods for the help and exit the trash compactor, finding yourself in the [familiar](https://adventofcode.com/2025/day/6) teleporter lab & its [tachyon](https://en.wikipedia.org/wiki/Tachyon) manifold.
+
+The manifold's diagram shows where a beam enters (`S`) and where the splitters (`^`) are \<in the way>:
+
+```
+..S..
+.....
+..^..
+.^.^.
+```
+
+A beam that hits a splitter is split into a *left* and a *right* beam:
+
+- The beam continues from `S` until it reaches `^`.
+- Each new beam moves down from just beside the splitter.
+
+In this example, the beam is split a total of *`3`* times.
+
+Analyze your manifold diagram. *How many times will the beam be split?*
+
+## Part Two
+
+With a *quantum* tachyon manifold, a single particle at `x_1` takes *both* paths at each splitter "at once", like \*this\*.
+
+```
+..S..
+..|..
+.|^|.
+|^|^|
+```
+
+In total, the particle ends up on *`4`* different timelines.
+"#
+        );
+    }
+
+    #[test]
+    fn articles_only_include_unlocked_parts() {
+        assert_eq!(articles(PART_ONE_PAGE).len(), 1);
+        assert_eq!(articles(BOTH_PARTS_PAGE).len(), 2);
+        assert!(articles("<main><p>Please log in.</p></main>").is_empty());
+        assert!(page_to_markdown("<main><p>Please log in.</p></main>", BASE_URL).is_err());
+    }
+
+    #[test]
+    fn example_is_the_block_after_for_example() {
+        assert_eq!(
+            likely_example(PART_ONE_PAGE).as_deref(),
+            Some("L68\nL30\nR48\n")
+        );
+        // there's no "For example", and part two's blocks aren't considered
+        assert_eq!(
+            likely_example(BOTH_PARTS_PAGE).as_deref(),
+            Some("..S..\n.....\n..^..\n.^.^.\n")
+        );
+    }
+}
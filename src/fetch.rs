@@ -0,0 +1,282 @@
+use std::{fs, io::ErrorKind, path::Path, thread::sleep, time::Duration};
+
+use anyhow::{Context, Result};
+use chrono::TimeDelta;
+
+use crate::{
+    calendar::{self, last_day},
+    client::AocClient,
+    description,
+    input::{self, example_path, input_path},
+};
+
+// how often fetching a day is attempted after waiting for it to unlock
+const UNLOCK_FETCH_ATTEMPTS: u32 = 5;
+
+#[derive(Default, Clone, Copy)]
+pub struct FetchOptions {
+    // also save the puzzle description as Markdown
+    pub description: bool,
+    // also save the example from the puzzle description
+    pub example: bool,
+    // fetch the input and example again even if they were fetched before
+    pub force: bool,
+}
+
+/// What fetch_day did, for the caller to report
+#[derive(Debug)]
+pub struct FetchReport {
+    // false if the input already existed and wasn't fetched again
+    pub input_fetched: bool,
+    // how many parts' description was saved, None unless asked for
+    pub description_parts: Option<usize>,
+    // None unless asked for
+    pub example: Option<SavedExample>,
+}
+
+#[derive(Debug)]
+pub enum SavedExample {
+    // the example that was picked from the puzzle and saved
+    Saved(String),
+    // an example was saved before, and was kept since it may have been corrected by hand
+    Kept,
+}
+
+// what ensure_input found wrong with a day's input
+pub enum InputProblem<'a> {
+    Missing,
+    // with why it looks broken
+    Broken(&'a anyhow::Error),
+}
+
+// how a day's input was found by fetch_all
+pub enum InputStatus {
+    // with the time until it unlocks
+    Locked(TimeDelta),
+    Exists,
+    Fetched,
+    Error(anyhow::Error),
+}
+
+/// Fetches a day's input, and its description and example if asked to. Inputs never change, so they're only
+/// fetched again if forced. A fetched input is validated before it's saved, and written atomically.
+pub fn fetch_day(
+    client: &AocClient,
+    year: i32,
+    day: u32,
+    options: FetchOptions,
+) -> Result<FetchReport> {
+    let path = input_path(year, day);
+    let input_fetched = !Path::new(&path).exists() || options.force;
+    if input_fetched {
+        let input = client.fetch_input(year, day)?;
+        input::validate(year, day, &input)
+            .context("The downloaded input looks broken, so it wasn't saved")?;
+        input::write_atomically(&path, &input)?;
+    }
+
+    let mut report = FetchReport {
+        input_fetched,
+        description_parts: None,
+        example: None,
+    };
+    if !options.description && !options.example {
+        return Ok(report);
+    }
+
+    let page = client
+        .fetch_puzzle(year, day)
+        .context("Couldn't fetch the puzzle description")?;
+    if options.description {
+        report.description_parts = Some(description::save_description(
+            &page,
+            client.base_url(),
+            year,
+            day,
+        )?);
+    }
+    if options.example {
+        report.example = Some(save_example(year, day, &page, options.force)?);
+    }
+
+    Ok(report)
+}
+
+// an example that already exists is kept unless forced, since it may have been corrected by hand
+fn save_example(year: i32, day: u32, page: &str, force: bool) -> Result<SavedExample> {
+    let path = example_path(year, day);
+    if Path::new(&path).exists() && !force {
+        return Ok(SavedExample::Kept);
+    }
+
+    let example =
+        description::likely_example(page).context("Couldn't find an example in the puzzle")?;
+    input::write_atomically(&path, &example)?;
+
+    Ok(SavedExample::Saved(example))
+}
+
+/// Makes sure a day's input is there before it's run. If it's missing or looks broken, should_fetch is asked
+/// whether to fetch it (again), and it's fetched if so. Returns whether it was fetched.
+pub fn ensure_input(
+    year: i32,
+    day: u32,
+    should_fetch: impl FnOnce(InputProblem) -> Result<bool>,
+) -> Result<bool> {
+    let path = input_path(year, day);
+    let problem = match fs::read_to_string(&path) {
+        Err(e) if e.kind() == ErrorKind::NotFound => None,
+        Err(e) => return Err(e).with_context(|| format!("Couldn't read {path}")),
+        Ok(input) => match input::check_complete(year, day, &input) {
+            Ok(()) => return Ok(false),
+            Err(e) => Some(e),
+        },
+    };
+
+    let fetch = match &problem {
+        None => should_fetch(InputProblem::Missing)?,
+        Some(e) => should_fetch(InputProblem::Broken(e))?,
+    };
+    if !fetch {
+        return Ok(false);
+    }
+
+    let options = FetchOptions {
+        // a broken input is replaced
+        force: problem.is_some(),
+        ..FetchOptions::default()
+    };
+    AocClient::new()
+        .and_then(|client| fetch_day(&client, year, day, options))
+        .context("Failed to fetch input")?;
+
+    Ok(true)
+}
+
+/// Fetches the input of every unlocked day of the year that hasn't been fetched yet. before_fetch is called
+/// with each day that is fetched, since the client spaces out the requests.
+pub fn fetch_all(year: i32, mut before_fetch: impl FnMut(u32)) -> Vec<(u32, InputStatus)> {
+    let now = calendar::now();
+
+    (1..=last_day(year))
+        .map(|day| {
+            let unlock = calendar::unlock_time(year, day);
+            let status = if unlock > now {
+                InputStatus::Locked(unlock - now)
+            } else if Path::new(&input_path(year, day)).exists() {
+                InputStatus::Exists
+            } else {
+                before_fetch(day);
+                match AocClient::new()
+                    .and_then(|client| fetch_day(&client, year, day, FetchOptions::default()))
+                {
+                    Ok(_) => InputStatus::Fetched,
+                    Err(e) => InputStatus::Error(e),
+                }
+            };
+            (day, status)
+        })
+        .collect()
+}
+
+/// Waits for the day to unlock, calling countdown with the remaining time about once a second.
+/// Returns the day that was waited for, which is the next day to unlock if no day is given.
+pub fn wait_for_unlock(
+    year: i32,
+    day: Option<u32>,
+    mut countdown: impl FnMut(u32, TimeDelta) -> Result<()>,
+) -> Result<u32> {
+    let day = match day {
+        Some(day) => day,
+        None => calendar::next_unlock(year, calendar::now()).with_context(|| {
+            format!("Every day of {year} is unlocked already, please specify a day")
+        })?,
+    };
+
+    let unlock = calendar::unlock_time(year, day);
+    loop {
+        let remaining = unlock - calendar::now();
+        if remaining <= TimeDelta::zero() {
+            return Ok(day);
+        }
+        countdown(day, remaining)?;
+        sleep(remaining.to_std()?.min(Duration::from_secs(1)));
+    }
+}
+
+/// Fetches a day that just unlocked. AoC can take a moment to serve the input after the unlock, so fetching is
+/// attempted a few times, calling on_retry with each error but the last. The client spaces out the attempts.
+pub fn fetch_when_unlocked(
+    year: i32,
+    day: u32,
+    options: FetchOptions,
+    mut on_retry: impl FnMut(&anyhow::Error),
+) -> Result<FetchReport> {
+    let client = AocClient::new()?;
+    let mut attempt = 1;
+    loop {
+        match fetch_day(&client, year, day, options) {
+            Err(e) if attempt < UNLOCK_FETCH_ATTEMPTS => on_retry(&e),
+            result => return result,
+        }
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::StubServer;
+
+    // every test shares the input directory, since it can only be set once
+    fn test_input_dir() {
+        let dir = std::env::temp_dir().join(format!("aoc-tests-{}", std::process::id()));
+        input::set_input_dir(dir.to_str().unwrap());
+    }
+
+    #[test]
+    fn existing_input_is_kept() {
+        test_input_dir();
+        input::write_atomically(&input_path(2025, 1), "L68\n").unwrap();
+        // nothing to answer the request if one is made
+        let server = StubServer::serve(vec![]);
+        let client = server.client();
+        server.requests();
+
+        let report = fetch_day(&client, 2025, 1, FetchOptions::default()).unwrap();
+
+        assert!(!report.input_fetched);
+        assert_eq!(fs::read_to_string(input_path(2025, 1)).unwrap(), "L68\n");
+    }
+
+    #[test]
+    fn existing_input_is_replaced_when_forced() {
+        test_input_dir();
+        input::write_atomically(&input_path(2025, 2), "broken").unwrap();
+        let server = StubServer::serve(vec![(200, "11-22,95-115\n")]);
+        let options = FetchOptions {
+            force: true,
+            ..FetchOptions::default()
+        };
+
+        let report = fetch_day(&server.client(), 2025, 2, options).unwrap();
+
+        assert!(report.input_fetched);
+        assert_eq!(server.requests().len(), 1);
+        assert_eq!(
+            fs::read_to_string(input_path(2025, 2)).unwrap(),
+            "11-22,95-115\n"
+        );
+    }
+
+    #[test]
+    fn broken_input_isnt_saved() {
+        test_input_dir();
+        let server = StubServer::serve(vec![(200, "<!DOCTYPE html>\n<html></html>\n")]);
+
+        let e = fetch_day(&server.client(), 2025, 3, FetchOptions::default()).unwrap_err();
+
+        assert!(format!("{e:#}").contains("The input is an HTML page"));
+        assert!(!Path::new(&input_path(2025, 3)).exists());
+    }
+}
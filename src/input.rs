@@ -0,0 +1,101 @@
+use std::{fs, path::Path, sync::OnceLock};
+
+use anyhow::{Context, Result, bail};
+use regex::Regex;
+
+use crate::days::find_day;
+
+const DEFAULT_INPUT_DIR: &str = "input";
+
+// set from input_dir in aoc.toml
+static INPUT_DIR: OnceLock<String> = OnceLock::new();
+
+pub fn set_input_dir(dir: &str) {
+    INPUT_DIR.get_or_init(|| dir.trim_end_matches('/').to_string());
+}
+
+pub fn input_dir() -> &'static str {
+    INPUT_DIR.get().map_or(DEFAULT_INPUT_DIR, String::as_str)
+}
+
+pub fn input_path(year: i32, day: u32) -> String {
+    format!("{}/{year}/day{:0>2}.txt", input_dir(), day)
+}
+
+// the example saved by fetch --example, for days without an example input in their source
+pub fn example_path(year: i32, day: u32) -> String {
+    format!("{}/{year}/day{:0>2}.example.txt", input_dir(), day)
+}
+
+/// Checks that a fetched input looks like an input rather than an error page. AoC sometimes responds to input
+/// requests with an explanation instead of the input, so the body is checked for those as well as for HTML and
+/// for lines that don't match the day's input pattern.
+pub fn validate(year: i32, day: u32, input: &str) -> Result<()> {
+    if input.trim().is_empty() {
+        bail!("The input is empty");
+    }
+    if input.contains("Please don't repeatedly request this endpoint before it unlocks") {
+        bail!("The input is AoC's message asking not to fetch it before the puzzle unlocks");
+    }
+    if input.contains("Please log in to get your puzzle input") {
+        bail!(
+            "The input is AoC's message asking you to log in, check your session token with token check"
+        );
+    }
+    let start = input
+        .trim_start()
+        .chars()
+        .take(20)
+        .collect::<String>()
+        .to_lowercase();
+    if start.starts_with("<!doctype html") || start.starts_with("<html") {
+        bail!("The input is an HTML page");
+    }
+
+    let Some(pattern) = find_day(year, day).and_then(|entry| entry.input_pattern) else {
+        return Ok(());
+    };
+    let pattern = Regex::new(pattern)
+        .with_context(|| format!("Day {day}'s input pattern {pattern:?} is not a valid regex"))?;
+    if let Some((index, line)) = input
+        .lines()
+        .enumerate()
+        .find(|(_, line)| !pattern.is_match(line))
+    {
+        bail!(
+            "Line {} of the input ({line:?}) doesn't look like day {day}'s input",
+            index + 1
+        );
+    }
+
+    Ok(())
+}
+
+/// Checks an input file for signs that it was cut off: AoC's inputs always end with a line break.
+/// Also checks everything validate checks, since the file may have been saved by hand.
+pub fn check_complete(year: i32, day: u32, input: &str) -> Result<()> {
+    validate(year, day, input)?;
+    if !input.ends_with('\n') {
+        bail!("The input doesn't end with a line break, so it was probably cut off");
+    }
+
+    Ok(())
+}
+
+// writes to a temporary file first and then renames it, so the file is either written completely or not at all
+// the file's directory is created if it doesn't exist yet
+pub fn write_atomically(path: &str, contents: &str) -> Result<()> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir).with_context(|| format!("Couldn't create {}", dir.display()))?;
+    }
+
+    let temp_path = format!("{path}.tmp");
+    fs::write(&temp_path, contents).with_context(|| format!("Couldn't write {temp_path}"))?;
+    if let Err(e) = fs::rename(&temp_path, path) {
+        // don't leave the temporary file behind
+        let _ = fs::remove_file(&temp_path);
+        return Err(e).with_context(|| format!("Couldn't move {temp_path} to {path}"));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+//! Solutions to Advent of Code puzzles, plus everything needed to run them, fetch their inputs and submit their
+//! answers. The `aoc` binary is the command line interface to this library.
+
+pub mod answers;
+pub mod calendar;
+pub mod client;
+pub mod config;
+pub mod days;
+pub mod description;
+pub mod fetch;
+pub mod input;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+#[cfg(test)]
+mod test_server;
+pub mod verify;
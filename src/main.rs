@@ -1,18 +1,37 @@
-mod days;
+mod output;
 
 use std::{
-    fs::{self, read_to_string},
-    path::Path,
+    io::{IsTerminal, Write, stdin, stdout},
+    ops::RangeInclusive,
+    path::PathBuf,
+    process::exit,
+    time::Duration,
 };
 
 use anyhow::{Context, Result, bail};
-use chrono::{Datelike, Local};
-use clap::{Parser, Subcommand};
-use days::*;
-use reqwest::{blocking::Client, header::COOKIE};
+use aoc::{
+    answers::Answers,
+    calendar::{self, FIRST_YEAR, MAX_DAY, last_day},
+    client::{self, AocClient, RetryCause},
+    config::{CONFIG_PATH, Config},
+    days::{Answer, find_day},
+    description,
+    fetch::{self, FetchOptions, FetchReport, InputProblem, InputStatus, SavedExample},
+    input::{self, example_path, input_path},
+    runner::{
+        self, DayBenchmark, DayReport, Failure, InputSource, RunOptions, RunReport, TimingStats,
+        part_name,
+    },
+    scaffold::{self, DAYS_MOD_PATH, ScaffoldReport},
+    submit,
+    verify::{self, Status},
+};
+use chrono::{DateTime, Utc};
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use output::OutputFormat;
 
-const YEAR: i32 = 2025;
-const LAST_DAY: i64 = 12;
+// the year used if neither --year nor the config file specify one
+const DEFAULT_YEAR: i32 = 2025;
 
 #[derive(Parser)]
 #[command(author, version)]
@@ -20,126 +39,968 @@ const LAST_DAY: i64 = 12;
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+    #[arg(
+        long,
+        global = true,
+        value_parser = clap::value_parser!(i32).range(i64::from(FIRST_YEAR)..),
+        help = "The year of the event (defaults to the year in aoc.toml, or 2025)"
+    )]
+    year: Option<i32>,
+    #[arg(
+        long,
+        global = true,
+        value_parser = parse_now,
+        help = "Pretend it's this time (e.g. 2025-12-05T06:00:00+01:00) when working out today's day and waiting for a day to unlock, for testing"
+    )]
+    now: Option<DateTime<Utc>>,
 }
 
 #[derive(Subcommand)]
 enum Commands {
-    /// Run your solution (specify the day or leave blank to run today,)
+    /// Run your solution (specify the day or range of days, or leave blank to run today)
     Run {
+        #[command(flatten)]
+        selection: DaySelection,
+        #[arg(
+            long,
+            conflicts_with = "input",
+            help = "Run on the example input from the puzzle description instead of your input"
+        )]
+        example: bool,
+        #[arg(
+            long,
+            value_name = "PATH",
+            help = "Run on the input in this file instead of input/<year>/dayNN.txt (use - to read from stdin)"
+        )]
+        input: Option<PathBuf>,
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = OutputFormat::Text,
+            help = "How to print the results"
+        )]
+        format: OutputFormat,
+        #[arg(
+            long,
+            conflicts_with_all = ["all", "example", "input"],
+            help = "Wait for the day (or the next day) to unlock, then fetch its input and run it"
+        )]
+        wait: bool,
+        #[arg(
+            long,
+            value_name = "SECONDS",
+            value_parser = parse_timeout,
+            help = "Give up on parsing or a part that takes longer than this (e.g. 10 or 0.5) and move on"
+        )]
+        timeout: Option<Duration>,
+    },
+    /// Compare your solutions' answers to the accepted answers in answers.toml (specify the day or range of days, or leave blank to verify every day)
+    Verify {
+        #[command(flatten)]
+        selection: DaySelection,
+        #[arg(
+            long,
+            help = "Verify the answers for the example inputs instead of your input"
+        )]
+        example: bool,
+        #[arg(
+            long,
+            value_name = "SECONDS",
+            value_parser = parse_timeout,
+            help = "Give up on parsing or a part that takes longer than this (e.g. 10 or 0.5) and move on"
+        )]
+        timeout: Option<Duration>,
+    },
+    /// Measure your solution's runtime over repeated runs (specify the day or range of days, or leave blank to benchmark today)
+    Bench {
+        #[command(flatten)]
+        selection: DaySelection,
         #[arg(
-            value_parser = clap::value_parser!(u32).range(1..={LAST_DAY}),
-            help = "The day you want to run (or leave blank to run today)")
+            long,
+            default_value_t = 10,
+            value_parser = clap::value_parser!(u32).range(1..),
+            help = "How many measured runs of each part to do"
+        )]
+        iterations: u32,
+        #[arg(
+            long,
+            default_value_t = 1,
+            help = "How many unmeasured runs of each part to do before measuring"
+        )]
+        warmup: u32,
+    },
+    /// Submit an answer to adventofcode.org (leave the answer blank to submit your solution's answer)
+    Submit {
+        #[arg(
+            value_parser = clap::value_parser!(u32).range(1..=i64::from(MAX_DAY)),
+            help = "The day you want to submit an answer for")
         ]
-        day: Option<u32>,
+        day: u32,
+        #[arg(
+            value_parser = clap::value_parser!(u8).range(1..=2),
+            help = "The part (1 or 2) you want to submit an answer for")
+        ]
+        part: u8,
+        #[arg(
+            help = "The answer you want to submit (or leave blank to run the day and submit its answer)"
+        )]
+        answer: Option<String>,
     },
     /// Download the input from adventofcode.org (specify the day or leave blank to fetch today)
     Fetch {
         #[arg(
-            value_parser = clap::value_parser!(u32).range(1..=LAST_DAY),
+            value_parser = clap::value_parser!(u32).range(1..=i64::from(MAX_DAY)),
             help = "The the day you want to fetch the input for (or leave blank to fetch today)")
         ]
         day: Option<u32>,
+        #[arg(
+            long,
+            help = "Also save the puzzle description as Markdown in puzzles/<year>/dayNN.md (run again after solving part one to add part two)"
+        )]
+        description: bool,
+        #[arg(
+            long,
+            help = "Also save the example input from the puzzle description in input/<year>/dayNN.example.txt"
+        )]
+        example: bool,
+        #[arg(
+            long,
+            help = "Download the input (and example) again even if it was already downloaded"
+        )]
+        force: bool,
+        #[arg(
+            long,
+            conflicts_with = "force",
+            help = "Wait for the day (or the next day) to unlock, then fetch it and run it"
+        )]
+        wait: bool,
+        #[arg(
+            long,
+            conflicts_with_all = ["day", "description", "example", "force", "wait"],
+            help = "Fetch the input of every unlocked day that hasn't been fetched yet"
+        )]
+        all: bool,
+    },
+    /// Manage the session token used to talk to adventofcode.com
+    Token {
+        #[command(subcommand)]
+        command: TokenCommand,
+    },
+    /// Create a day's solution module from the template and register it
+    Scaffold {
+        #[arg(
+            value_parser = clap::value_parser!(u32).range(1..=i64::from(MAX_DAY)),
+            help = "The day you want to create a module for"
+        )]
+        day: u32,
+        #[arg(long, help = "Replace the day's module if it already exists")]
+        force: bool,
     },
 }
 
-fn today() -> Result<u32> {
-    match Local::now() {
-        date if date.year() == YEAR
-            && date.month() == 12
-            && date.day() >= 1
-            && i64::from(date.day()) <= LAST_DAY =>
-        {
-            Ok(date.day())
-        }
-        date => bail!(
-            "{}-{}-{} is not a valid AoC {} day!",
-            date.year(),
-            date.month(),
-            date.day(),
-            YEAR
-        ),
+#[derive(Subcommand)]
+enum TokenCommand {
+    /// Check that AoC accepts your session token and show who it belongs to
+    Check,
+}
+
+#[derive(Args)]
+struct DaySelection {
+    #[arg(
+        value_parser = parse_days,
+        conflicts_with = "all",
+        help = "The day (e.g. 3) or range of days (e.g. 3..7, both inclusive)")
+    ]
+    days: Option<RangeInclusive<u32>>,
+    #[arg(long, help = "Select every day (prints a summary table after running)")]
+    all: bool,
+}
+
+fn parse_now(now: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(now)
+        .map(|now| now.to_utc())
+        .map_err(|e| format!("'{now}' is not a time like 2025-12-05T06:00:00+01:00: {e}"))
+}
+
+fn parse_timeout(seconds: &str) -> Result<Duration, String> {
+    seconds
+        .parse()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .filter(|timeout| !timeout.is_zero())
+        .ok_or(format!("'{seconds}' is not a positive number of seconds"))
+}
+
+// days are checked against the selected year's calendar in check_days, once the year is known
+fn parse_days(days: &str) -> Result<RangeInclusive<u32>, String> {
+    let parse_day = |day: &str| match day.trim().parse::<u32>() {
+        Ok(day) if (1..=MAX_DAY).contains(&day) => Ok(day),
+        _ => Err(format!("'{day}' is not a day between 1 and {MAX_DAY}")),
+    };
+
+    match days.split_once("..") {
+        Some((from, to)) => {
+            let (from, to) = (parse_day(from)?, parse_day(to.trim_start_matches('='))?);
+            if from > to {
+                return Err(format!("{from}..{to} is an empty range of days"));
+            }
+            Ok(from..=to)
+        }
+        None => parse_day(days).map(|day| day..=day),
     }
 }
 
-fn run_day(day: &Option<u32>) -> Result<()> {
-    let day = match day {
-        Some(day) => *day,
-        None => today().context(
-            "Failed to get/parse today's date, please call the program again and specify a day",
-        )?,
+impl DaySelection {
+    fn resolve(&self, year: i32, default_to_all: bool) -> Result<RangeInclusive<u32>> {
+        match (&self.days, self.all || default_to_all) {
+            (Some(days), _) => Ok(days.clone()),
+            (None, true) => Ok(1..=last_day(year)),
+            (None, false) => {
+                let day = calendar::today(year).context(
+                    "There is no puzzle today, please call the program again and specify a day",
+                )?;
+                Ok(day..=day)
+            }
+        }
+    }
+
+    // for commands that only work on one day, returns None if no day was given
+    fn single_day(&self) -> Result<Option<u32>> {
+        match &self.days {
+            Some(days) if days.start() != days.end() => bail!("Only a single day can be selected"),
+            days => Ok(days.as_ref().map(|days| *days.start())),
+        }
+    }
+}
+
+// returns whether every day could be run
+fn run_days(
+    year: i32,
+    selection: &DaySelection,
+    options: RunOptions,
+    format: OutputFormat,
+) -> Result<bool> {
+    let days = selection.resolve(year, false)?;
+    if matches!(options.input, InputSource::File(_) | InputSource::Stdin)
+        && days.start() != days.end()
+    {
+        bail!("A custom input can only be used when running a single day");
+    }
+    let single_day = days.start() == days.end();
+
+    let mut report = RunReport::default();
+    for day in days {
+        report
+            .days
+            .push(run_day(year, day, &options, format != OutputFormat::Text));
+    }
+
+    match format {
+        OutputFormat::Text if single_day => {}
+        OutputFormat::Text => print_summary(&report),
+        format => output::print_records(format, &output::records(&report))?,
+    }
+
+    Ok(report.succeeded())
+}
+
+fn input_source(example: bool, input: &Option<PathBuf>) -> InputSource {
+    match (example, input) {
+        (true, _) => InputSource::Example,
+        (false, Some(path)) if path.as_os_str() == "-" => InputSource::Stdin,
+        (false, Some(path)) => InputSource::File(path.clone()),
+        (false, None) => InputSource::Default,
+    }
+}
+
+fn print_summary(report: &RunReport) {
+    let rows: Vec<[String; 4]> = report
+        .days
+        .iter()
+        .flat_map(|day| match &day.parts {
+            // the parse time gets its own row before the parts'
+            Ok(parts) => day
+                .parse_elapsed
+                .map(|elapsed| {
+                    [
+                        day.day.to_string(),
+                        "parse".to_string(),
+                        "-".to_string(),
+                        format!("{elapsed:?}"),
+                    ]
+                })
+                .into_iter()
+                .chain(parts.iter().map(|part| {
+                    [
+                        day.day.to_string(),
+                        part.part.to_string(),
+                        match &part.answer {
+                            Ok(answer) => answer.to_string(),
+                            Err(e) => match Failure::of(e) {
+                                Failure::Error => "failed".to_string(),
+                                Failure::Panic => "panicked".to_string(),
+                                Failure::Timeout => "timed out".to_string(),
+                            },
+                        },
+                        // the pass that solved both parts is only shown once
+                        match (day.solved_together, part.part) {
+                            (true, 2) => "(with part 1)".to_string(),
+                            _ => format!("{:?}", part.elapsed),
+                        },
+                    ]
+                }))
+                .collect(),
+            Err(_) => vec![[
+                day.day.to_string(),
+                "-".to_string(),
+                "not run".to_string(),
+                "-".to_string(),
+            ]],
+        })
+        .collect();
+
+    println!("--- Summary ---");
+    println!();
+    print_table(
+        ["Day", "Part", "Answer", "Elapsed time"],
+        &rows,
+        [true, true, false, true],
+    );
+    println!();
+
+    let not_run = report.days.iter().filter(|day| day.parts.is_err()).count();
+    let failed = report.parts().filter(|part| part.answer.is_err()).count();
+    println!("Total elapsed time: {:?}", report.total_elapsed());
+    if not_run > 0 {
+        println!("{not_run} day(s) could not be run");
+    }
+    if failed > 0 {
+        println!("{failed} part(s) failed");
+    }
+}
+
+fn print_table<const N: usize>(header: [&str; N], rows: &[[String; N]], right_align: [bool; N]) {
+    let header = header.map(String::from);
+    let widths: Vec<usize> = (0..N)
+        .map(|column| {
+            rows.iter()
+                .chain([&header])
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let print_row = |row: &[String; N]| {
+        println!(
+            "{}",
+            row.iter()
+                .enumerate()
+                .map(|(column, cell)| match right_align[column] {
+                    true => format!("{cell:>width$}", width = widths[column]),
+                    false => format!("{cell:<width$}", width = widths[column]),
+                })
+                .collect::<Vec<String>>()
+                .join(" | ")
+                .trim_end()
+        )
     };
 
-    if !Path::new(&format!("input/day{day:02}.txt")).exists() {
-        println!("No input found, attempting to fetch from AoC website.");
-        match fetch_day(&Some(day)) {
-            Ok(()) => (),
-            Err(e) => bail!("Failed to fetch input: {e}"),
+    print_row(&header);
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<String>>()
+            .join("-+-")
+    );
+    rows.iter().for_each(print_row);
+}
+
+// returns whether every recorded answer matched
+fn verify_days(
+    year: i32,
+    selection: &DaySelection,
+    example: bool,
+    timeout: Option<Duration>,
+) -> Result<bool> {
+    let days = selection.resolve(year, true)?;
+    let answers = Answers::load()?;
+    let options = RunOptions {
+        input: match example {
+            true => InputSource::Example,
+            false => InputSource::Default,
+        },
+        timeout,
+    };
+
+    // a regression check doesn't fetch anything, a missing input is reported instead
+    let report = runner::run(year, days, &options);
+    let verification = verify::verify(&answers, &report, example);
+    let answer_or_dash = |answer: &Option<Answer>| {
+        answer
+            .as_ref()
+            .map_or("-".to_string(), |answer| answer.to_string())
+    };
+    let rows: Vec<[String; 5]> = verification
+        .verifications
+        .iter()
+        .map(|verification| {
+            [
+                verification.day.to_string(),
+                verification
+                    .part
+                    .map_or("-".to_string(), |part| part.to_string()),
+                verification.status.to_string(),
+                answer_or_dash(&verification.expected),
+                answer_or_dash(&verification.actual),
+            ]
+        })
+        .collect();
+
+    println!("--- Verification ---");
+    println!();
+    print_table(
+        ["Day", "Part", "Status", "Expected", "Actual"],
+        &rows,
+        [true, true, false, false, false],
+    );
+    println!();
+
+    println!(
+        "{} passed, {} failed, {} errors, {} missing, {} unsolved",
+        verification.count(Status::Pass),
+        verification.count(Status::Fail),
+        // the status tells panics and timeouts apart, the count doesn't
+        verification.failed_to_run(),
+        verification.count(Status::Missing),
+        verification.count(Status::Unsolved),
+    );
+
+    Ok(verification.succeeded())
+}
+
+// progress is printed to stderr when quiet, so it doesn't end up in machine-readable output
+fn ensure_input(year: i32, day: u32, quiet: bool) -> Result<()> {
+    let say = |message: &str| match quiet {
+        true => eprintln!("{message}"),
+        false => println!("{message}"),
+    };
+
+    let fetched = fetch::ensure_input(year, day, |problem| match problem {
+        InputProblem::Missing => {
+            say("No input found, attempting to fetch from AoC website.");
+            Ok(true)
         }
+        InputProblem::Broken(e) => {
+            say(&format!("{} looks broken: {e:#}", input_path(year, day)));
+            let refetch = offer_refetch(quiet)?;
+            if !refetch {
+                say(&format!(
+                    "Running it anyway, fetch it again with fetch {day} --year {year} --force"
+                ));
+            }
+            Ok(refetch)
+        }
+    })?;
+    if fetched && !quiet {
         println!();
     }
 
-    match day {
-        1 => day01::Day01::run(),
-        2 => day02::Day02::run(),
-        3 => day03::Day03::run(),
-        4 => day04::Day04::run(),
-        5 => day05::Day05::run(),
-        6 => day06::Day06::run(),
-        7 => day07::Day07::run(),
-        8 => day08::Day08::run(),
-        9 => day09::Day09::run(),
-        10 => day10::Day10::run(),
-        11 => day11::Day11::run(),
-        12 => day12::Day12::run(),
-        day => bail!("The Advent of Code {{YEAR}} doesn't have a day {day}"),
+    Ok(())
+}
+
+// asks whether to fetch a broken input again, which is only possible in a terminal
+fn offer_refetch(quiet: bool) -> Result<bool> {
+    if quiet || !stdin().is_terminal() {
+        return Ok(false);
+    }
+
+    print!("Fetch it again? [y/N] ");
+    stdout().flush()?;
+    let mut answer = String::new();
+    stdin().read_line(&mut answer)?;
+
+    Ok(answer.trim().eq_ignore_ascii_case("y"))
+}
+
+// fetches the day's input first if it's needed and missing, and prints the results unless quiet
+fn run_day(year: i32, day: u32, options: &RunOptions, quiet: bool) -> DayReport {
+    if options.input == InputSource::Default
+        && let Some(entry) = find_day(year, day)
+        && let Err(e) = ensure_input(year, day, quiet)
+    {
+        let report = DayReport {
+            year,
+            day,
+            name: Some(entry.name),
+            input: options.input.name(year, day),
+            parse_elapsed: None,
+            solved_together: false,
+            parts: Err(e),
+        };
+        if !quiet {
+            print_day(&report);
+        }
+        return report;
+    }
+
+    let report = runner::run_day(year, day, options);
+    if !quiet {
+        print_day(&report);
+    }
+    report
+}
+
+fn print_day(report: &DayReport) {
+    let parts = match &report.parts {
+        Ok(parts) => parts,
+        Err(e) => {
+            println!("Couldn't run day {}: {e:#}", report.day);
+            println!();
+            return;
+        }
+    };
+    // only days that are implemented can have been run
+    let name = report.name.unwrap_or("???");
+
+    match report.input == input_path(report.year, report.day) {
+        true => println!("--- Day {}: {name} ---", report.day),
+        false => println!("--- Day {}: {name} ({}) ---", report.day, report.input),
+    }
+    println!();
+
+    if let Some(elapsed) = report.parse_elapsed {
+        println!("- Parsing input -");
+        println!("Elapsed time: {elapsed:?}");
+        println!();
+    }
+    if report.solved_together {
+        println!("- Solving both parts -");
+        for part in parts {
+            match &part.answer {
+                Ok(answer) => println!("Result (part {}): {answer}", part_name(part.part)),
+                Err(e) => println!("{e:#}"),
+            }
+        }
+        if let Some(part) = parts.first() {
+            println!("Elapsed time: {:?}", part.elapsed);
+        }
+        println!();
+        return;
+    }
+    for part in parts {
+        let part_name = part_name(part.part);
+        println!("- Starting part {part_name} -");
+        match &part.answer {
+            Ok(answer) => println!("Result (part {part_name}): {answer}"),
+            Err(e) => println!("{e:#}"),
+        }
+        println!("Elapsed time: {:?}", part.elapsed);
+        println!();
+    }
+}
+
+// returns whether every day could be benchmarked
+fn bench_days(year: i32, selection: &DaySelection, iterations: u32, warmup: u32) -> Result<bool> {
+    let mut succeeded = true;
+    let mut rows = vec![];
+    for day in selection.resolve(year, false)? {
+        let benchmark = match bench_day(year, day, iterations, warmup) {
+            Ok(benchmark) => benchmark,
+            Err(e) => {
+                println!("Couldn't benchmark day {day}: {e:#}");
+                println!();
+                succeeded = false;
+                continue;
+            }
+        };
+
+        let parse = ("parse".to_string(), TimingStats::of(&benchmark.parse));
+        let parts = benchmark
+            .parts
+            .iter()
+            .map(|part| (part.part.to_string(), part.stats()));
+        let both = benchmark
+            .both
+            .as_ref()
+            .map(|samples| ("both".to_string(), TimingStats::of(samples)));
+        for (part, stats) in [parse].into_iter().chain(parts).chain(both) {
+            rows.push([
+                benchmark.day.to_string(),
+                part,
+                format!("{:?}", stats.min),
+                format!("{:?}", stats.median),
+                format!("{:?}", stats.mean),
+                format!("{:?}", stats.std_dev),
+            ]);
+        }
+    }
+
+    println!("--- Benchmark ({iterations} iterations, {warmup} warmup) ---");
+    println!();
+    print_table(
+        ["Day", "Part", "Min", "Median", "Mean", "Std dev"],
+        &rows,
+        [true; 6],
+    );
+
+    Ok(succeeded)
+}
+
+fn bench_day(year: i32, day: u32, iterations: u32, warmup: u32) -> Result<DayBenchmark> {
+    let entry = find_day(year, day)
+        .with_context(|| format!("Day {day} of {year} is not implemented yet"))?;
+
+    ensure_input(year, day, false)?;
+
+    println!("--- Day {}: {} ---", day, entry.name);
+    println!("Benchmarking parsing and both parts ({iterations} iterations, {warmup} warmup)");
+    let benchmark = runner::bench_day(year, day, iterations, warmup)?;
+    println!();
+
+    Ok(benchmark)
+}
+
+fn submit_day(year: i32, day: u32, part: u8, answer: &Option<String>) -> Result<()> {
+    let answer = match answer {
+        Some(answer) => answer.clone(),
+        None => submit::answer_to_submit(run_day(year, day, &RunOptions::default(), false), part)?,
+    };
+
+    println!("Submitting {answer} as the answer to part {part} of day {day} of {year}.");
+    let verdict = submit::submit(year, day, part, &answer)?;
+    println!("{verdict}");
+
+    // solving part one unlocks part two's description
+    if verdict == submit::Verdict::Correct
+        && part == 1
+        && description::update_saved_description(year, day)
+            .context("Couldn't update the puzzle description")?
+    {
+        println!(
+            "Added part two to {}",
+            description::description_path(year, day)
+        );
     }
 
     Ok(())
 }
 
-fn fetch_day(day: &Option<u32>) -> Result<()> {
+fn fetch_day(year: i32, day: &Option<u32>, options: FetchOptions) -> Result<()> {
     let day = match day {
         Some(day) => *day,
-        None => match today() {
-            Ok(day) => day,
-            Err(e) => bail!("Can't get current day: {e}"),
-        },
+        None => calendar::today(year)
+            .context("There is no puzzle today, please call the program again and specify a day")?,
     };
 
-    let token = read_to_string(".token").context("Failed to read .token file")?;
+    let report = fetch::fetch_day(&AocClient::new()?, year, day, options)?;
+    print_fetch_report(year, day, &report);
 
-    let client = Client::new();
-    let response = client
-        .get(format!("https://adventofcode.com/{YEAR}/day/{day}/input"))
-        .header(COOKIE, format!("session={token};"))
-        .send()?
-        .error_for_status()
-        .context("Couldn't retrieve input from AoC website. Did you put your session cookie in the .token file?")?;
+    Ok(())
+}
+
+fn print_fetch_report(year: i32, day: u32, report: &FetchReport) {
+    if !report.input_fetched {
+        println!(
+            "{} already exists, pass --force to download it again",
+            input_path(year, day)
+        );
+    }
+    if let Some(parts) = report.description_parts {
+        println!(
+            "Saved the description of {} to {}",
+            match parts {
+                1 => "part one",
+                _ => "both parts",
+            },
+            description::description_path(year, day)
+        );
+    }
+    match &report.example {
+        Some(SavedExample::Saved(example)) => {
+            println!(
+                "Saved this example to {}, edit the file if it's not the right one:",
+                example_path(year, day)
+            );
+            println!();
+            print!("{example}");
+        }
+        Some(SavedExample::Kept) => println!(
+            "{} already exists, pass --force to replace it",
+            example_path(year, day)
+        ),
+        None => {}
+    }
+}
+
+// returns whether every unlocked day's input could be fetched
+fn fetch_all(year: i32) -> bool {
+    let statuses = fetch::fetch_all(year, |day| println!("Fetching day {day}"));
+
+    let rows: Vec<[String; 3]> = statuses
+        .iter()
+        .map(|(day, status)| {
+            let (status, details) = match status {
+                InputStatus::Locked(remaining) => (
+                    "LOCKED",
+                    format!("unlocks in {}", calendar::format_countdown(*remaining)),
+                ),
+                InputStatus::Exists => ("EXISTS", input_path(year, *day)),
+                InputStatus::Fetched => ("FETCHED", input_path(year, *day)),
+                InputStatus::Error(e) => ("ERROR", format!("{e:#}")),
+            };
+            [day.to_string(), status.to_string(), details]
+        })
+        .collect();
 
-    let input = response
-        .text()
-        .context("Failed to parse the website's response")?;
-    let path = format!("input/day{day:02}.txt");
-    fs::write(&path, input).context("Couldn't write input to file")?;
+    println!();
+    println!("--- Inputs for {year} ---");
+    println!();
+    print_table(["Day", "Status", "Details"], &rows, [true, false, false]);
+
+    rows.iter().all(|row| row[1] != "ERROR")
+}
+
+// waits for the day to unlock, fetches it as soon as AoC makes it available and runs it
+fn wait_and_run(
+    year: i32,
+    day: Option<u32>,
+    options: FetchOptions,
+    format: OutputFormat,
+    timeout: Option<Duration>,
+) -> Result<bool> {
+    let day = fetch::wait_for_unlock(year, day, |day, remaining| {
+        print!(
+            "\rDay {day} unlocks in {}",
+            calendar::format_countdown(remaining)
+        );
+        Ok(stdout().flush()?)
+    })?;
+    println!("\rDay {day} is unlocked!          ");
+
+    let report = fetch::fetch_when_unlocked(year, day, options, |e| {
+        println!("Couldn't fetch day {day} yet ({e:#}), trying again");
+    })?;
+    print_fetch_report(year, day, &report);
+    println!();
+
+    run_days(
+        year,
+        &DaySelection {
+            days: Some(day..=day),
+            all: false,
+        },
+        RunOptions {
+            input: InputSource::Default,
+            timeout,
+        },
+        format,
+    )
+}
+
+fn print_scaffold_report(day: u32, report: &ScaffoldReport) {
+    if let Some(year_mod_path) = &report.created_year {
+        println!("Created {year_mod_path} and registered it in {DAYS_MOD_PATH}");
+    }
+    println!("Created {}", report.day_path);
+    if let Some(year_mod_path) = &report.registered_in {
+        println!("Registered day {day} in {year_mod_path}");
+    }
+}
+
+fn check_token(year: i32) -> Result<()> {
+    let (_, source) = client::session_token()?;
+    println!("Using the session token from {source}");
+
+    let user = AocClient::new()?.logged_in_user(year)?;
+    println!("AoC accepts the token, you are logged in as {user}");
 
     Ok(())
 }
 
+impl Commands {
+    // the last day selected on the command line, which clap can only check against the longest calendar
+    fn last_selected_day(&self) -> Option<u32> {
+        match self {
+            Commands::Run { selection, .. }
+            | Commands::Verify { selection, .. }
+            | Commands::Bench { selection, .. } => selection.days.as_ref().map(|days| *days.end()),
+            Commands::Submit { day, .. } | Commands::Scaffold { day, .. } => Some(*day),
+            Commands::Fetch { day, .. } => *day,
+            Commands::Token { .. } => None,
+        }
+    }
+}
+
 fn main() {
     let cli = Cli::parse();
+    if let Some(now) = cli.now {
+        calendar::override_now(now);
+    }
+    // on stderr, so it doesn't end up in machine-readable output
+    client::on_retry(|retry| match &retry.cause {
+        RetryCause::Unreachable(e) => {
+            eprintln!("Couldn't reach AoC ({e}), retrying in {:?}", retry.delay)
+        }
+        RetryCause::ServerError(status) => {
+            eprintln!("AoC responded with {status}, retrying in {:?}", retry.delay)
+        }
+    });
 
-    match &cli.command {
-        Some(Commands::Run { day }) => match run_day(day) {
-            Ok(()) => (),
-            Err(e) => println!("Couldn't run day: {e}"),
+    let config = Config::load().unwrap_or_else(|e| {
+        println!("Couldn't read {CONFIG_PATH}: {e:#}");
+        exit(1);
+    });
+    // --year takes precedence over the config file
+    let year = cli.year.or(config.year).unwrap_or(DEFAULT_YEAR);
+    if let Some(input_dir) = &config.input_dir {
+        input::set_input_dir(input_dir);
+    }
+    if let Some(day) = cli.command.as_ref().and_then(Commands::last_selected_day)
+        && day > last_day(year)
+    {
+        Cli::command()
+            .error(
+                clap::error::ErrorKind::ValueValidation,
+                format!(
+                    "'{day}' is not a day of AoC {year}, which has {} days",
+                    last_day(year)
+                ),
+            )
+            .exit();
+    }
+
+    let succeeded = match &cli.command {
+        Some(Commands::Run {
+            selection,
+            format,
+            wait: true,
+            timeout,
+            ..
+        }) => selection
+            .single_day()
+            .and_then(|day| wait_and_run(year, day, FetchOptions::default(), *format, *timeout))
+            .unwrap_or_else(|e| {
+                println!("Couldn't run day: {e:#}");
+                false
+            }),
+        Some(Commands::Run {
+            selection,
+            example,
+            input,
+            format,
+            wait: false,
+            timeout,
+        }) => {
+            let options = RunOptions {
+                input: input_source(*example, input),
+                timeout: *timeout,
+            };
+            run_days(year, selection, options, *format).unwrap_or_else(|e| {
+                println!("Couldn't run day: {e:#}");
+                false
+            })
+        }
+        Some(Commands::Bench {
+            selection,
+            iterations,
+            warmup,
+        }) => bench_days(year, selection, *iterations, *warmup).unwrap_or_else(|e| {
+            println!("Couldn't benchmark day: {e:#}");
+            false
+        }),
+        Some(Commands::Submit { day, part, answer }) => match submit_day(year, *day, *part, answer)
+        {
+            Ok(()) => true,
+            Err(e) => {
+                println!("Couldn't submit answer: {e:#}");
+                false
+            }
+        },
+        Some(Commands::Fetch { all: true, .. }) => fetch_all(year),
+        Some(Commands::Fetch {
+            day,
+            description,
+            example,
+            wait: true,
+            ..
+        }) => {
+            let options = FetchOptions {
+                description: *description,
+                example: *example,
+                force: false,
+            };
+            wait_and_run(year, *day, options, OutputFormat::Text, None).unwrap_or_else(|e| {
+                println!("Couldn't fetch day: {e:#}");
+                false
+            })
+        }
+        Some(Commands::Fetch {
+            day,
+            description,
+            example,
+            force,
+            wait: false,
+            all: false,
+        }) => match fetch_day(
+            year,
+            day,
+            FetchOptions {
+                description: *description,
+                example: *example,
+                force: *force,
+            },
+        ) {
+            Ok(()) => true,
+            Err(e) => {
+                println!("Couldn't fetch day: {e:#}");
+                false
+            }
         },
-        Some(Commands::Fetch { day }) => match fetch_day(day) {
-            Ok(()) => (),
-            Err(e) => println!("Couldn't fetch day: {e}"),
+        Some(Commands::Token {
+            command: TokenCommand::Check,
+        }) => match check_token(year) {
+            Ok(()) => true,
+            Err(e) => {
+                println!("Couldn't check token: {e:#}");
+                false
+            }
         },
-        None => match run_day(&None) {
-            Ok(()) => (),
-            Err(e) => println!("Couldn't run today: {e}"),
+        Some(Commands::Scaffold { day, force }) => match scaffold::scaffold(year, *day, *force) {
+            Ok(report) => {
+                print_scaffold_report(*day, &report);
+                true
+            }
+            Err(e) => {
+                println!("Couldn't scaffold day: {e:#}");
+                false
+            }
         },
+        Some(Commands::Verify {
+            selection,
+            example,
+            timeout,
+        }) => verify_days(year, selection, *example, *timeout).unwrap_or_else(|e| {
+            println!("Couldn't verify days: {e:#}");
+            false
+        }),
+        None => run_days(
+            year,
+            &DaySelection {
+                days: None,
+                all: false,
+            },
+            RunOptions::default(),
+            OutputFormat::Text,
+        )
+        .unwrap_or_else(|e| {
+            println!("Couldn't run today: {e:#}");
+            false
+        }),
+    };
+
+    if !succeeded {
+        exit(1);
     }
 }
@@ -0,0 +1,160 @@
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+
+use aoc::{
+    days::Answer,
+    runner::{Failure, RunReport},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Progress and results as they happen, plus a summary table when running several days
+    Text,
+    /// A JSON array with one object per part
+    Json,
+    /// One line per part, with a header line
+    Csv,
+    /// A Markdown table with one row per part
+    Markdown,
+}
+
+/// One part's result, or a day that couldn't be run (without a part, answer and duration).
+/// Parts that failed, aren't solved yet or have nothing to solve have no answer, a failed part has an error instead
+#[derive(Serialize)]
+pub struct Record {
+    year: i32,
+    day: u32,
+    part: Option<u8>,
+    #[serde(serialize_with = "serialize_answer")]
+    answer: Option<Answer>,
+    duration_ns: Option<u64>,
+    // the day's input is parsed once for both parts, so both of its records have the same parse duration
+    parse_duration_ns: Option<u64>,
+    input: String,
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+// integer answers are JSON numbers, so tools can compare them numerically
+fn serialize_answer<S: Serializer>(
+    answer: &Option<Answer>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match answer {
+        Some(Answer::Int(answer)) => serializer.serialize_i64(*answer),
+        Some(Answer::BigInt(answer)) => serializer.serialize_i128(*answer),
+        Some(answer) => serializer.serialize_str(&answer.to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+// the status of a part or day that couldn't be run
+fn error_status(e: &anyhow::Error) -> &'static str {
+    match Failure::of(e) {
+        Failure::Error => "error",
+        Failure::Panic => "panic",
+        Failure::Timeout => "timeout",
+    }
+}
+
+pub fn records(report: &RunReport) -> Vec<Record> {
+    report
+        .days
+        .iter()
+        .flat_map(|day| match &day.parts {
+            Ok(parts) => parts
+                .iter()
+                .map(|part| Record {
+                    year: day.year,
+                    day: day.day,
+                    part: Some(part.part),
+                    answer: part
+                        .answer
+                        .as_ref()
+                        .ok()
+                        .filter(|answer| answer.is_answer())
+                        .cloned(),
+                    // a pass that solved both parts is only counted once, for part one
+                    duration_ns: Some(part.elapsed.as_nanos() as u64)
+                        .filter(|_| !(day.solved_together && part.part == 2)),
+                    parse_duration_ns: day.parse_elapsed.map(|elapsed| elapsed.as_nanos() as u64),
+                    input: day.input.clone(),
+                    status: match &part.answer {
+                        Err(e) => error_status(e),
+                        Ok(Answer::Unsolved) => "unsolved",
+                        Ok(Answer::NotApplicable) => "not_applicable",
+                        Ok(_) => "ok",
+                    },
+                    error: part.answer.as_ref().err().map(|e| format!("{e:#}")),
+                })
+                .collect(),
+            Err(e) => vec![Record {
+                year: day.year,
+                day: day.day,
+                part: None,
+                answer: None,
+                duration_ns: None,
+                parse_duration_ns: None,
+                input: day.input.clone(),
+                status: error_status(e),
+                error: Some(format!("{e:#}")),
+            }],
+        })
+        .collect()
+}
+
+pub fn print_records(format: OutputFormat, records: &[Record]) -> Result<()> {
+    let optional = |value: Option<String>| value.unwrap_or_default();
+    let duration = |ns: Option<u64>| {
+        optional(ns.map(|ns| format!("{:?}", std::time::Duration::from_nanos(ns))))
+    };
+
+    match format {
+        OutputFormat::Text => unreachable!("text output is printed while running"),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(records)?),
+        OutputFormat::Csv => {
+            println!("year,day,part,answer,duration_ns,parse_duration_ns,input,status");
+            for record in records {
+                println!(
+                    "{},{},{},{},{},{},{},{}",
+                    record.year,
+                    record.day,
+                    optional(record.part.map(|part| part.to_string())),
+                    csv_field(&optional(record.answer.as_ref().map(Answer::to_string))),
+                    optional(record.duration_ns.map(|ns| ns.to_string())),
+                    optional(record.parse_duration_ns.map(|ns| ns.to_string())),
+                    csv_field(&record.input),
+                    record.status
+                );
+            }
+        }
+        OutputFormat::Markdown => {
+            println!("| Year | Day | Part | Answer | Elapsed time | Parse time | Input | Status |");
+            println!("| --: | --: | --: | :-- | --: | --: | :-- | :-- |");
+            for record in records {
+                println!(
+                    "| {} | {} | {} | {} | {} | {} | {} | {} |",
+                    record.year,
+                    record.day,
+                    optional(record.part.map(|part| part.to_string())),
+                    optional(record.answer.as_ref().map(Answer::to_string)).replace('|', "\\|"),
+                    duration(record.duration_ns),
+                    duration(record.parse_duration_ns),
+                    record.input,
+                    record.status
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\n']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
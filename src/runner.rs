@@ -0,0 +1,684 @@
+use std::{
+    cell::Cell,
+    error::Error,
+    fmt::{self, Display},
+    fs::read_to_string,
+    hint::black_box,
+    io::stdin,
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Once,
+        mpsc::{self, RecvTimeoutError},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result, anyhow, bail};
+
+use crate::{
+    days::{Answer, DayEntry, ParsedInput, find_day},
+    input::{example_path, input_path},
+};
+
+// the stack a worker thread gets, as much as the main thread usually has, for solutions that recurse deeply
+const WORKER_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+static QUIET_PANIC_HOOK: Once = Once::new();
+
+thread_local! {
+    // set while catch_panic runs code on this thread, whose panics are reported instead of printed
+    static CATCHING_PANICS: Cell<bool> = const { Cell::new(false) };
+    // where the last caught panic on this thread happened, for its report
+    static PANIC_LOCATION: Cell<Option<String>> = const { Cell::new(None) };
+}
+
+#[derive(Default, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    // <input dir>/<year>/dayNN.txt
+    #[default]
+    Default,
+    // the day's example input(s)
+    Example,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    // how the input is referred to in output
+    pub fn name(&self, year: i32, day: u32) -> String {
+        match self {
+            InputSource::Default => input_path(year, day),
+            // a fetched example is used instead of the one in the day's source
+            InputSource::Example if Path::new(&example_path(year, day)).exists() => {
+                example_path(year, day)
+            }
+            InputSource::Example => "example".to_string(),
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Stdin => "stdin".to_string(),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct RunOptions {
+    pub input: InputSource,
+    // how long parsing and each part may take, no limit if None
+    pub timeout: Option<Duration>,
+}
+
+/// The error of a part (or of parsing) that didn't finish, as opposed to one that returned an error.
+/// It's wrapped in the usual context, so find it with `downcast_ref`, or tell failures apart with `Failure::of`.
+#[derive(Debug, Clone)]
+pub enum Aborted {
+    // with the panic's message
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl Display for Aborted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Aborted::Panicked(message) => write!(f, "panicked: {message}"),
+            Aborted::TimedOut(timeout) => write!(f, "timed out after {timeout:?}"),
+        }
+    }
+}
+
+impl Error for Aborted {}
+
+/// How a part or day that couldn't be run failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Failure {
+    Error,
+    Panic,
+    Timeout,
+}
+
+impl Failure {
+    pub fn of(e: &anyhow::Error) -> Failure {
+        match e.downcast_ref::<Aborted>() {
+            Some(Aborted::Panicked(_)) => Failure::Panic,
+            Some(Aborted::TimedOut(_)) => Failure::Timeout,
+            None => Failure::Error,
+        }
+    }
+}
+
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+}
+
+impl PartReport {
+    // whether the part returned an actual answer, rather than failing or having none
+    pub fn solved(&self) -> bool {
+        self.answer.as_ref().is_ok_and(Answer::is_answer)
+    }
+}
+
+pub struct DayReport {
+    pub year: i32,
+    pub day: u32,
+    // None if the day isn't implemented
+    pub name: Option<&'static str>,
+    pub input: String,
+    // how long parsing the input took, None if it wasn't parsed
+    pub parse_elapsed: Option<Duration>,
+    // whether both parts were solved in one pass by solve_both, whose elapsed time is in both parts' reports
+    pub solved_together: bool,
+    // an error if the day couldn't be run at all, e.g. because its input couldn't be read or parsed
+    pub parts: Result<Vec<PartReport>>,
+}
+
+impl DayReport {
+    // the time spent parsing and solving, counting a pass that solved both parts once
+    pub fn elapsed(&self) -> Duration {
+        let parts = self.parts.iter().flatten().map(|part| part.elapsed);
+        let solve_elapsed: Duration = match self.solved_together {
+            true => parts.max().unwrap_or_default(),
+            false => parts.sum(),
+        };
+        self.parse_elapsed.unwrap_or_default() + solve_elapsed
+    }
+
+    pub fn succeeded(&self) -> bool {
+        self.parts
+            .as_ref()
+            .is_ok_and(|parts| parts.iter().all(|part| part.answer.is_ok()))
+    }
+}
+
+/// The answers, durations and errors of running one or more days, in the order they were run
+#[derive(Default)]
+pub struct RunReport {
+    pub days: Vec<DayReport>,
+}
+
+impl RunReport {
+    pub fn succeeded(&self) -> bool {
+        self.days.iter().all(DayReport::succeeded)
+    }
+
+    pub fn parts(&self) -> impl Iterator<Item = &PartReport> {
+        self.days
+            .iter()
+            .filter_map(|day| day.parts.as_ref().ok())
+            .flatten()
+    }
+
+    // includes the time spent parsing
+    pub fn total_elapsed(&self) -> Duration {
+        self.days.iter().map(DayReport::elapsed).sum()
+    }
+}
+
+pub struct PartBenchmark {
+    pub part: u8,
+    pub samples: Vec<Duration>,
+}
+
+pub struct DayBenchmark {
+    pub day: u8,
+    // the elapsed time of each time the input was parsed
+    pub parse: Vec<Duration>,
+    pub parts: Vec<PartBenchmark>,
+    // the elapsed time of each pass of solve_both, for days registered with it
+    pub both: Option<Vec<Duration>>,
+}
+
+pub struct TimingStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+impl PartBenchmark {
+    pub fn stats(&self) -> TimingStats {
+        TimingStats::of(&self.samples)
+    }
+}
+
+impl TimingStats {
+    // there has to be at least one sample, which bench_day makes sure of
+    pub fn of(samples: &[Duration]) -> TimingStats {
+        let mut samples: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+        samples.sort_by(f64::total_cmp);
+
+        let count = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / count;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        let median = match samples.len() % 2 {
+            0 => (samples[samples.len() / 2 - 1] + samples[samples.len() / 2]) / 2.0,
+            _ => samples[samples.len() / 2],
+        };
+
+        TimingStats {
+            min: Duration::from_secs_f64(samples[0]),
+            median: Duration::from_secs_f64(median),
+            mean: Duration::from_secs_f64(mean),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+// "one" or "two", as parts are referred to in output
+pub fn part_name(part: u8) -> &'static str {
+    match part {
+        1 => "one",
+        _ => "two",
+    }
+}
+
+fn read_input(year: i32, day: u8) -> Result<String> {
+    let path = input_path(year, day.into());
+    read_to_string(&path).with_context(|| {
+        format!(
+            "Couldn't read {path}, fetch it with fetch {day} --year {year}. Not running this day"
+        )
+    })
+}
+
+// returns the inputs for part one and part two
+fn read_inputs(entry: &DayEntry, input: &InputSource) -> Result<(String, String)> {
+    let (year, day) = (entry.year, entry.day);
+
+    match input {
+        InputSource::Default => {
+            let input = read_input(year, day)?;
+            Ok((input.clone(), input))
+        }
+        InputSource::Example => {
+            // a fetched example takes precedence over the one in the day's source
+            let fetched = read_to_string(example_path(year, day.into())).ok();
+            let input_one = fetched
+                .as_deref()
+                .or(entry.example_input)
+                .with_context(|| {
+                    format!(
+                        "Day {} has no example input. Fetch one with fetch --example",
+                        day
+                    )
+                })?;
+            // part two keeps its own example from the source, if it has one
+            let input_two = match entry.example_input_part_two {
+                Some(input_two) if entry.example_input_part_two != entry.example_input => input_two,
+                _ => input_one,
+            };
+            Ok((input_one.to_string(), input_two.to_string()))
+        }
+        InputSource::File(path) => {
+            let input = read_to_string(path)
+                .with_context(|| format!("Couldn't read input file {}", path.display()))?;
+            Ok((input.clone(), input))
+        }
+        InputSource::Stdin => {
+            let input =
+                std::io::read_to_string(stdin()).context("Couldn't read input from stdin")?;
+            Ok((input.clone(), input))
+        }
+    }
+}
+
+// replaces the panic hook with one that stays quiet on threads that are catching panics, and only
+// remembers where the panic happened. Panics anywhere else are still printed by the previous hook
+fn install_quiet_panic_hook() {
+    QUIET_PANIC_HOOK.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| match CATCHING_PANICS.get() {
+            true => PANIC_LOCATION.set(info.location().map(ToString::to_string)),
+            false => previous_hook(info),
+        }));
+    });
+}
+
+// turns a panic in solve into an error, with the panic's message and location
+fn catch_panic<T>(solve: impl FnOnce() -> Result<T>) -> Result<T> {
+    install_quiet_panic_hook();
+    let was_catching = CATCHING_PANICS.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(solve));
+    CATCHING_PANICS.set(was_catching);
+
+    result.unwrap_or_else(|payload| {
+        // panic! with a literal has a &str payload, with format arguments a String
+        let message = match payload.downcast_ref::<&str>() {
+            Some(message) => message.to_string(),
+            None => payload
+                .downcast_ref::<String>()
+                .cloned()
+                .unwrap_or("unknown panic".to_string()),
+        };
+        let message = match PANIC_LOCATION.take() {
+            Some(location) => format!("{message} at {location}"),
+            None => message,
+        };
+        Err(Aborted::Panicked(message).into())
+    })
+}
+
+/// Runs solve and returns its result and elapsed time, with a panic turned into an error.
+/// With a timeout, solve runs on a worker thread that is given up on when the time runs out. Threads can't be
+/// stopped, so it keeps running in the background until it finishes or the program exits.
+fn isolate<T: Send + 'static>(
+    timeout: Option<Duration>,
+    solve: impl FnOnce() -> Result<T> + Send + 'static,
+) -> (Result<T>, Duration) {
+    let run = move || {
+        let start_time = Instant::now();
+        let result = catch_panic(solve);
+        (result, start_time.elapsed())
+    };
+    let Some(timeout) = timeout else {
+        return run();
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let worker = thread::Builder::new()
+        .stack_size(WORKER_STACK_SIZE)
+        .spawn(move || {
+            // the receiver is gone if it stopped waiting
+            let _ = sender.send(run());
+        });
+    if let Err(e) = worker {
+        return (
+            Err(e).context("Couldn't start a worker thread"),
+            Duration::ZERO,
+        );
+    }
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => (Err(Aborted::TimedOut(timeout).into()), timeout),
+        // run catches panics, so the worker always sends something
+        Err(RecvTimeoutError::Disconnected) => (
+            Err(anyhow!("The worker thread stopped without a result")),
+            Duration::ZERO,
+        ),
+    }
+}
+
+fn run_part(
+    day: u8,
+    part: u8,
+    solve: fn(&ParsedInput) -> Result<Answer>,
+    parsed: &Arc<ParsedInput>,
+    timeout: Option<Duration>,
+) -> PartReport {
+    let parsed = Arc::clone(parsed);
+    let (answer, elapsed) = isolate(timeout, move || solve(&*parsed));
+
+    PartReport {
+        part,
+        answer: answer.with_context(|| format!("Day {day} part {} failed", part_name(part))),
+        elapsed,
+    }
+}
+
+// returns the parsed input and how long parsing took
+fn parse(
+    entry: &DayEntry,
+    input: &str,
+    source: &InputSource,
+    timeout: Option<Duration>,
+) -> Result<(Arc<ParsedInput>, Duration)> {
+    let parse = match source {
+        InputSource::Example => entry.parse_example,
+        _ => entry.parse,
+    };
+    let input = input.to_string();
+    let (parsed, elapsed) = isolate(timeout, move || parse(&input));
+    let parsed = parsed.with_context(|| format!("Couldn't parse day {}'s input", entry.day))?;
+
+    Ok((Arc::from(parsed), elapsed))
+}
+
+// runs solve_both, returning None if the day isn't registered with it
+// the pass's elapsed time is reported for both parts, and so is its error if it fails
+fn run_both(
+    entry: &DayEntry,
+    parsed: &Arc<ParsedInput>,
+    timeout: Option<Duration>,
+) -> Option<Vec<PartReport>> {
+    let (solve_both, parsed) = (entry.solve_both?, Arc::clone(parsed));
+    let (answers, elapsed) = isolate(timeout, move || solve_both(&*parsed));
+
+    let (answer_one, answer_two) = match answers {
+        Ok((answer_one, answer_two)) => (Ok(answer_one), Ok(answer_two)),
+        Err(e) => {
+            let context = format!("Day {} failed solving both parts", entry.day);
+            // part two gets a copy of the error, which keeps telling whether the pass was aborted
+            let e_two = match e.downcast_ref::<Aborted>() {
+                Some(aborted) => anyhow::Error::new(aborted.clone()).context(context.clone()),
+                None => anyhow!("{context}: {e:#}"),
+            };
+            (Err(e.context(context)), Err(e_two))
+        }
+    };
+
+    Some(vec![
+        PartReport {
+            part: 1,
+            answer: answer_one,
+            elapsed,
+        },
+        PartReport {
+            part: 2,
+            answer: answer_two,
+            elapsed,
+        },
+    ])
+}
+
+/// Parses a day's input and runs both parts on it, without fetching the input or printing anything.
+/// Days registered with solve_both are solved in one pass, unless part two has an input of its own.
+/// An error, panic or timeout in part one doesn't keep part two from being run.
+pub fn run_day(year: i32, day: u32, options: &RunOptions) -> DayReport {
+    let entry = find_day(year, day);
+    let mut parse_elapsed = None;
+    let mut solved_together = false;
+    let parts = entry
+        .with_context(|| format!("Day {day} of {year} is not implemented yet"))
+        .and_then(|entry| {
+            let (input_one, input_two) = read_inputs(&entry, &options.input)?;
+            let timeout = options.timeout;
+            let (parsed_one, mut elapsed) = parse(&entry, &input_one, &options.input, timeout)?;
+            // the parts only have different inputs if part two has its own example
+            let parsed_two = match input_two == input_one {
+                true => None,
+                false => {
+                    let (parsed_two, elapsed_two) =
+                        parse(&entry, &input_two, &options.input, timeout)?;
+                    elapsed += elapsed_two;
+                    Some(parsed_two)
+                }
+            };
+            parse_elapsed = Some(elapsed);
+
+            if parsed_two.is_none()
+                && let Some(parts) = run_both(&entry, &parsed_one, timeout)
+            {
+                solved_together = true;
+                return Ok(parts);
+            }
+            Ok(vec![
+                run_part(entry.day, 1, entry.part_one, &parsed_one, timeout),
+                run_part(
+                    entry.day,
+                    2,
+                    entry.part_two,
+                    parsed_two.as_ref().unwrap_or(&parsed_one),
+                    timeout,
+                ),
+            ])
+        });
+
+    DayReport {
+        year,
+        day,
+        name: entry.map(|entry| entry.name),
+        input: options.input.name(year, day),
+        parse_elapsed,
+        solved_together,
+        parts,
+    }
+}
+
+/// Runs each of the days in turn, see run_day
+pub fn run(year: i32, days: RangeInclusive<u32>, options: &RunOptions) -> RunReport {
+    RunReport {
+        days: days.map(|day| run_day(year, day, options)).collect(),
+    }
+}
+
+// runs solve warmup times without measuring, then returns the elapsed time of each of the following iterations
+// a panic is returned as an error, there's no timeout
+fn measure<T>(
+    iterations: u32,
+    warmup: u32,
+    solve: impl Fn() -> Result<T>,
+) -> Result<Vec<Duration>> {
+    catch_panic(|| {
+        for _ in 0..warmup {
+            black_box(solve()?);
+        }
+
+        (0..iterations)
+            .map(|_| {
+                let start_time = Instant::now();
+                black_box(solve()?);
+                Ok(start_time.elapsed())
+            })
+            .collect()
+    })
+}
+
+/// Parses a day's input and runs each part on it repeatedly, see measure.
+/// The input is read before any measurements are taken, and parsed once more before the parts are measured,
+/// so parsing and each part are timed on their own. Days registered with solve_both also get it measured,
+/// after the parts are measured separately.
+pub fn bench_day(year: i32, day: u32, iterations: u32, warmup: u32) -> Result<DayBenchmark> {
+    if iterations == 0 {
+        bail!("At least one iteration is needed to benchmark day {day}");
+    }
+    let entry = find_day(year, day)
+        .with_context(|| format!("Day {day} of {year} is not implemented yet"))?;
+    let input = read_input(year, entry.day)?;
+
+    let samples_parse = measure(iterations, warmup, || (entry.parse)(black_box(&input)))
+        .with_context(|| format!("Couldn't parse day {day}'s input"))?;
+    let parsed = (entry.parse)(&input)?;
+    let samples_one = measure(iterations, warmup, || (entry.part_one)(black_box(&*parsed)))
+        .with_context(|| format!("Day {} part one failed", day))?;
+    let samples_two = measure(iterations, warmup, || (entry.part_two)(black_box(&*parsed)))
+        .with_context(|| format!("Day {} part two failed", day))?;
+    let samples_both = match entry.solve_both {
+        Some(solve_both) => Some(
+            measure(iterations, warmup, || solve_both(black_box(&*parsed)))
+                .with_context(|| format!("Day {} failed solving both parts", day))?,
+        ),
+        None => None,
+    };
+
+    Ok(DayBenchmark {
+        day: entry.day,
+        parse: samples_parse,
+        both: samples_both,
+        parts: vec![
+            PartBenchmark {
+                part: 1,
+                samples: samples_one,
+            },
+            PartBenchmark {
+                part: 2,
+                samples: samples_two,
+            },
+        ],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn timing_stats() {
+        let stats = TimingStats::of(&millis(&[4, 1, 3, 2]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.std_dev.as_micros(), 1118);
+
+        let stats = TimingStats::of(&millis(&[5, 1, 9]));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn zero_iterations_cant_be_benchmarked() {
+        let e = bench_day(2025, 1, 0, 0).err().unwrap();
+        assert_eq!(
+            e.to_string(),
+            "At least one iteration is needed to benchmark day 1"
+        );
+    }
+
+    #[test]
+    fn errors_are_errors() {
+        let (result, _) = isolate(None, || Err::<(), _>(anyhow!("wrong input")));
+        let e = result.unwrap_err().context("Day 1 part one failed");
+        assert_eq!(Failure::of(&e), Failure::Error);
+    }
+
+    #[test]
+    fn panics_are_caught_with_their_location() {
+        let (result, _) = isolate(None, || -> Result<()> {
+            panic!("index {} out of bounds", 3)
+        });
+        let e = result.unwrap_err().context("Day 1 part one failed");
+
+        assert_eq!(Failure::of(&e), Failure::Panic);
+        let Some(Aborted::Panicked(message)) = e.downcast_ref::<Aborted>() else {
+            panic!("not a panic: {e:#}");
+        };
+        assert!(
+            message.starts_with("index 3 out of bounds at src/runner.rs:"),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn panics_on_worker_threads_are_caught() {
+        let timeout = Some(Duration::from_secs(10));
+        let (result, _) = isolate(timeout, || -> Result<()> { panic!("on a worker") });
+
+        assert_eq!(Failure::of(&result.unwrap_err()), Failure::Panic);
+    }
+
+    #[test]
+    fn slow_parts_time_out() {
+        let timeout = Duration::from_millis(10);
+        let (result, elapsed) = isolate(Some(timeout), || {
+            thread::sleep(Duration::from_secs(1));
+            Ok(())
+        });
+
+        assert_eq!(Failure::of(&result.unwrap_err()), Failure::Timeout);
+        assert_eq!(elapsed, timeout);
+    }
+
+    fn run_example(day: u32) -> DayReport {
+        let options = RunOptions {
+            input: InputSource::Example,
+            timeout: None,
+        };
+        run_day(2025, day, &options)
+    }
+
+    #[test]
+    fn days_with_solve_both_are_solved_together() {
+        let report = run_example(4);
+
+        assert!(report.solved_together);
+        let parts = report.parts.as_ref().unwrap();
+        assert_eq!(parts[0].answer.as_ref().unwrap(), &Answer::Int(13));
+        assert_eq!(parts[1].answer.as_ref().unwrap(), &Answer::Int(43));
+        // the pass is counted once
+        assert_eq!(
+            report.elapsed(),
+            report.parse_elapsed.unwrap() + parts[0].elapsed
+        );
+    }
+
+    #[test]
+    fn other_days_are_solved_part_by_part() {
+        let report = run_example(2);
+
+        assert!(!report.solved_together);
+        assert!(report.succeeded());
+        let parts = report.parts.as_ref().unwrap();
+        assert_eq!(
+            report.elapsed(),
+            report.parse_elapsed.unwrap() + parts[0].elapsed + parts[1].elapsed
+        );
+    }
+
+    #[test]
+    fn unimplemented_days_cant_be_run() {
+        let report = run_example(13);
+
+        assert_eq!(report.name, None);
+        assert!(!report.succeeded());
+    }
+}
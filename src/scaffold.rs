@@ -0,0 +1,182 @@
+use std::{
+    fs::{self, read_to_string},
+    path::Path,
+};
+
+use anyhow::{Context, Result, bail};
+use regex::Regex;
+
+pub const DAYS_MOD_PATH: &str = "src/days/mod.rs";
+
+// {day} is replaced with the day's number, {day:02} with the zero-padded number
+const DAY_TEMPLATE: &str = r#"use anyhow::Result;
+
+use crate::days::{Answer, Day};
+
+pub struct Day{day:02};
+
+// paste the example from the puzzle description in here, or save it to a file with fetch --example
+const EXAMPLE_INPUT: &str = "";
+
+impl Day for Day{day:02} {
+    // change this to whatever both parts need from the input
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        Ok(input.lines().map(String::from).collect())
+    }
+    fn part_one(_lines: &Vec<String>) -> Result<impl Into<Answer>> {
+        Ok(Answer::Unsolved)
+    }
+    fn part_two(_lines: &Vec<String>) -> Result<impl Into<Answer>> {
+        Ok(Answer::Unsolved)
+    }
+    fn get_day_num() -> u8 {
+        {day}
+    }
+    fn get_name() -> &'static str {
+        "???"
+    }
+    // there is no example until one is pasted into EXAMPLE_INPUT
+    fn example_input() -> Option<&'static str> {
+        Some(EXAMPLE_INPUT).filter(|input| !input.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "fill in the example's answer"]
+    fn part_one_example() {
+        let parsed = Day{day:02}::parse_example(EXAMPLE_INPUT).unwrap();
+        let answer: Answer = Day{day:02}::part_one(&parsed).unwrap().into();
+        assert_eq!(answer, Answer::Int(0));
+    }
+
+    #[test]
+    #[ignore = "fill in the example's answer"]
+    fn part_two_example() {
+        let parsed = Day{day:02}::parse_example(EXAMPLE_INPUT).unwrap();
+        let answer: Answer = Day{day:02}::part_two(&parsed).unwrap().into();
+        assert_eq!(answer, Answer::Int(0));
+    }
+}
+"#;
+
+/// What scaffold created, for the caller to report
+pub struct ScaffoldReport {
+    pub day_path: String,
+    // the year's module, if it was new and had to be created and registered in DAYS_MOD_PATH
+    pub created_year: Option<String>,
+    // the year's module the day was registered in, None if it was registered already
+    pub registered_in: Option<String>,
+}
+
+fn day_path(year: i32, day: u32) -> String {
+    format!("src/days/y{year}/day{day:02}.rs")
+}
+
+/// Creates a day's module from the template and registers it, adding the year's module if it's new.
+/// An existing day module is only replaced if forced.
+pub fn scaffold(year: i32, day: u32, force: bool) -> Result<ScaffoldReport> {
+    let path = day_path(year, day);
+    if Path::new(&path).exists() && !force {
+        bail!("{path} already exists, pass --force to replace it with the template");
+    }
+
+    let year_mod_path = format!("src/days/y{year}/mod.rs");
+    let mut created_year = None;
+    if !Path::new(&year_mod_path).exists() {
+        fs::create_dir_all(format!("src/days/y{year}"))
+            .with_context(|| format!("Couldn't create the module directory for {year}"))?;
+        fs::write(
+            &year_mod_path,
+            format!("register_days! {{\n    {year};\n}}\n"),
+        )
+        .with_context(|| format!("Couldn't write {year_mod_path}"))?;
+        register_year(year)?;
+        created_year = Some(year_mod_path.clone());
+    }
+
+    let day_str = DAY_TEMPLATE
+        .replace("{day:02}", &format!("{day:02}"))
+        .replace("{day}", &day.to_string());
+    fs::write(&path, day_str).with_context(|| format!("Couldn't write {path}"))?;
+    let registered = register_day(&year_mod_path, day)?;
+
+    Ok(ScaffoldReport {
+        day_path: path,
+        created_year,
+        registered_in: registered.then_some(year_mod_path),
+    })
+}
+
+// inserts line before the first of the lines matching pattern that sorts after it, or after the last of them
+fn insert_sorted(source: &str, pattern: &Regex, line: &str) -> Result<String> {
+    let mut lines: Vec<&str> = source.lines().collect();
+    let matching: Vec<usize> = (0..lines.len())
+        .filter(|index| pattern.is_match(lines[*index]))
+        .collect();
+    let index = match matching.iter().find(|index| lines[**index] > line) {
+        Some(index) => *index,
+        None => {
+            matching
+                .last()
+                .context("Couldn't find where to insert the line")?
+                + 1
+        }
+    };
+    lines.insert(index, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// returns whether the day was added to the year's register_days! invocation, rather than already being in it
+fn register_day(year_mod_path: &str, day: u32) -> Result<bool> {
+    let source =
+        read_to_string(year_mod_path).with_context(|| format!("Couldn't read {year_mod_path}"))?;
+    let line = format!("    day{day:02}::Day{day:02},");
+    if source.lines().any(|existing| existing == line) {
+        return Ok(false);
+    }
+    // a day replaced with the template no longer has the solve_both function it may have been registered with
+    let with_solve_both = Regex::new(&format!(r"^    day{day:02}::Day{day:02} \+ \w+,$"))?;
+    let source = source
+        .lines()
+        .filter(|existing| !with_solve_both.is_match(existing))
+        .collect::<Vec<&str>>()
+        .join("\n");
+
+    // the year's literal precedes the days, so a year without days gets its first day after it
+    let pattern = Regex::new(r"^    (day\d{2}::Day\d{2}( \+ \w+)?,|\d{4};)$")?;
+    let source = insert_sorted(&source, &pattern, &line).with_context(|| {
+        format!("Couldn't find the register_days! invocation in {year_mod_path}")
+    })?;
+    fs::write(year_mod_path, source).with_context(|| format!("Couldn't write {year_mod_path}"))?;
+
+    Ok(true)
+}
+
+// declares the year's module and adds it to the registry in src/days/mod.rs
+fn register_year(year: i32) -> Result<()> {
+    let source =
+        read_to_string(DAYS_MOD_PATH).with_context(|| format!("Couldn't read {DAYS_MOD_PATH}"))?;
+
+    let source = insert_sorted(
+        &source,
+        &Regex::new(r"^pub mod y\d{4};$")?,
+        &format!("pub mod y{year};"),
+    )
+    .and_then(|source| {
+        insert_sorted(
+            &source,
+            &Regex::new(r"^        \d{4} => y\d{4}::registry\(\),$")?,
+            &format!("        {year} => y{year}::registry(),"),
+        )
+    })
+    .with_context(|| format!("Couldn't find the year modules and registry in {DAYS_MOD_PATH}"))?;
+
+    fs::write(DAYS_MOD_PATH, source).with_context(|| format!("Couldn't write {DAYS_MOD_PATH}"))
+}
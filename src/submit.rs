@@ -0,0 +1,378 @@
+use std::{
+    fmt::Display,
+    fs::{OpenOptions, read_to_string},
+    io::Write,
+    path::Path,
+    time::Duration,
+};
+
+use anyhow::{Context, Result, bail};
+use chrono::Utc;
+use regex::Regex;
+
+use crate::{answers, client::AocClient, days::Answer, runner::DayReport};
+
+pub const SUBMISSIONS_PATH: &str = "submissions.log";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    Wait(Duration),
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl Verdict {
+    fn log_name(&self) -> &str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait(_) => "wait",
+            Verdict::AlreadySolved => "already_solved",
+            Verdict::Unknown(_) => "unknown",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::TooHigh => write!(f, "That's not the right answer, it's too high."),
+            Verdict::TooLow => write!(f, "That's not the right answer, it's too low."),
+            Verdict::Wrong => write!(f, "That's not the right answer."),
+            Verdict::Wait(wait) => write!(
+                f,
+                "You gave an answer too recently, wait {}s before trying again.",
+                wait.as_secs()
+            ),
+            Verdict::AlreadySolved => write!(f, "This part has already been solved."),
+            Verdict::Unknown(message) => write!(f, "Unrecognized response: {message}"),
+        }
+    }
+}
+
+// the verdict is the text of the page's only <article> element
+pub fn parse_verdict(page: &str) -> Verdict {
+    let article_re = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    let tag_re = Regex::new(r"<[^>]*>").unwrap();
+    let wait_re = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+
+    let article = article_re
+        .captures(page)
+        .and_then(|article| article.get(1))
+        .map_or(page, |article| article.as_str());
+    let message = tag_re
+        .replace_all(article, "")
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ");
+
+    if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else if message.contains("You gave an answer too recently") {
+        let seconds = wait_re
+            .captures(&message)
+            .map(|wait| {
+                let minutes = wait.get(1).map_or(0, |m| m.as_str().parse().unwrap_or(0));
+                let seconds: u64 = wait[2].parse().unwrap_or(0);
+                minutes * 60 + seconds
+            })
+            .unwrap_or(0);
+        Verdict::Wait(Duration::from_secs(seconds))
+    } else if message.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if message.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if message.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else {
+        Verdict::Unknown(message)
+    }
+}
+
+struct Submission {
+    year: i32,
+    day: u32,
+    part: u8,
+    answer: String,
+    verdict: String,
+}
+
+/// Every answer submitted so far, one tab-separated line per submission in submissions.log
+pub struct SubmissionLog {
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn load() -> Result<SubmissionLog> {
+        if !Path::new(SUBMISSIONS_PATH).exists() {
+            return Ok(SubmissionLog {
+                submissions: vec![],
+            });
+        }
+
+        let log = read_to_string(SUBMISSIONS_PATH)
+            .with_context(|| format!("Couldn't read {SUBMISSIONS_PATH}"))?;
+        SubmissionLog::parse(&log)
+    }
+
+    fn parse(log: &str) -> Result<SubmissionLog> {
+        let submissions = log
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(line_num, line)| {
+                // timestamp, year, day, part, answer, verdict
+                let fields: Vec<&str> = line.split('\t').collect();
+                match fields[..] {
+                    [_, year, day, part, answer, verdict] => Ok(Submission {
+                        year: year.parse()?,
+                        day: day.parse()?,
+                        part: part.parse()?,
+                        answer: answer.to_string(),
+                        verdict: verdict.to_string(),
+                    }),
+                    _ => bail!("Malformed line {} in {SUBMISSIONS_PATH}", line_num + 1),
+                }
+            })
+            .collect::<Result<Vec<Submission>>>()?;
+
+        Ok(SubmissionLog { submissions })
+    }
+
+    // returns why the answer shouldn't be submitted, if previous submissions show it's wrong
+    pub fn check(&self, year: i32, day: u32, part: u8, answer: &str) -> Option<String> {
+        let previous: Vec<&Submission> = self
+            .submissions
+            .iter()
+            .filter(|submission| {
+                submission.year == year && submission.day == day && submission.part == part
+            })
+            .collect();
+
+        if let Some(correct) = previous.iter().find(|s| s.verdict == "correct") {
+            return Some(format!(
+                "Part {part} of day {day} was already solved with {}",
+                correct.answer
+            ));
+        }
+
+        if let Some(wrong) = previous.iter().find(|s| {
+            s.answer == answer && ["wrong", "too_high", "too_low"].contains(&s.verdict.as_str())
+        }) {
+            return Some(format!(
+                "{answer} was already submitted and was {}",
+                wrong.verdict.replace('_', " ")
+            ));
+        }
+
+        let answer_num: i128 = answer.parse().ok()?;
+        previous.iter().find_map(|submission| {
+            let previous_num: i128 = submission.answer.parse().ok()?;
+            match submission.verdict.as_str() {
+                "too_high" if answer_num >= previous_num => Some(format!(
+                    "{answer} is too high, {previous_num} was already too high"
+                )),
+                "too_low" if answer_num <= previous_num => Some(format!(
+                    "{answer} is too low, {previous_num} was already too low"
+                )),
+                _ => None,
+            }
+        })
+    }
+
+    fn record(year: i32, day: u32, part: u8, answer: &str, verdict: &Verdict) -> Result<()> {
+        let mut log = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(SUBMISSIONS_PATH)
+            .with_context(|| format!("Couldn't open {SUBMISSIONS_PATH}"))?;
+        writeln!(
+            log,
+            "{}\t{year}\t{day}\t{part}\t{answer}\t{}",
+            Utc::now().to_rfc3339(),
+            verdict.log_name()
+        )
+        .with_context(|| format!("Couldn't write to {SUBMISSIONS_PATH}"))
+    }
+}
+
+/// Picks a part's answer out of the report of running its day, refusing parts without an answer to submit
+pub fn answer_to_submit(report: DayReport, part: u8) -> Result<String> {
+    let day = report.day;
+    let result = report
+        .parts?
+        .into_iter()
+        .find(|result| result.part == part)
+        .with_context(|| format!("Day {day} has no part {part}"))?;
+
+    match result.answer? {
+        Answer::Unsolved => bail!("Part {part} of day {day} has no solution yet"),
+        Answer::NotApplicable => bail!("Part {part} of day {day} has no answer to submit"),
+        answer => Ok(answer.to_string()),
+    }
+}
+
+pub fn submit(year: i32, day: u32, part: u8, answer: &str) -> Result<Verdict> {
+    let answer = answer.trim();
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        bail!("'{answer}' is not a valid answer");
+    }
+
+    if let Some(reason) = SubmissionLog::load()?.check(year, day, part, answer) {
+        bail!("Not submitting: {reason}");
+    }
+
+    let page = AocClient::new()?.post_answer(year, day, part, answer)?;
+    let verdict = parse_verdict(&page);
+
+    SubmissionLog::record(year, day, part, answer, &verdict)?;
+    if verdict == Verdict::Correct {
+        answers::record(year, day, part, answer)?;
+    }
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::StubServer;
+
+    fn verdict_page(message: &str) -> String {
+        format!(
+            r#"<!DOCTYPE html><html><body><main><article><p>{message}</p></article></main></body></html>"#
+        )
+    }
+
+    #[test]
+    fn verdicts() {
+        let pages = [
+            (
+                r#"That's the right answer!  You are <span class="day-success">one gold star</span> closer to decorating the North Pole. [<a href="/2025/day/1#part2">Continue to Part Two</a>]"#,
+                Verdict::Correct,
+            ),
+            (
+                r#"That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. [<a href="/2025/day/1">Return to Day 1</a>]"#,
+                Verdict::TooHigh,
+            ),
+            (
+                r#"That's not the right answer; your answer is too low.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. [<a href="/2025/day/1">Return to Day 1</a>]"#,
+                Verdict::TooLow,
+            ),
+            (
+                r#"That's not the right answer.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. [<a href="/2025/day/1">Return to Day 1</a>]"#,
+                Verdict::Wrong,
+            ),
+            (
+                r#"You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait. [<a href="/2025/day/1">Return to Day 1</a>]"#,
+                Verdict::Wait(Duration::from_secs(272)),
+            ),
+            (
+                r#"You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 45s left to wait. [<a href="/2025/day/1">Return to Day 1</a>]"#,
+                Verdict::Wait(Duration::from_secs(45)),
+            ),
+            (
+                r#"You don't seem to be solving the right level.  Did you already complete it? [<a href="/2025/day/1">Return to Day 1</a>]"#,
+                Verdict::AlreadySolved,
+            ),
+            (
+                "Something <em>new</em>\n happened.",
+                Verdict::Unknown("Something new happened.".to_string()),
+            ),
+        ];
+
+        for (message, verdict) in pages {
+            assert_eq!(parse_verdict(&verdict_page(message)), verdict, "{message}");
+        }
+    }
+
+    // timestamp, year, day, part, answer, verdict
+    const LOG: &str = "\
+2025-12-01T05:01:00+00:00\t2025\t1\t1\t500\ttoo_high
+2025-12-01T05:02:00+00:00\t2025\t1\t1\t100\ttoo_low
+2025-12-01T05:03:00+00:00\t2025\t1\t1\t250\twrong
+
+2025-12-02T05:01:00+00:00\t2025\t2\t1\t1234\tcorrect
+2025-12-02T05:02:00+00:00\t2025\t2\t2\tabc\twrong
+";
+
+    #[test]
+    fn checks_against_previous_submissions() {
+        let log = SubmissionLog::parse(LOG).unwrap();
+
+        assert_eq!(
+            log.check(2025, 1, 1, "600").as_deref(),
+            Some("600 is too high, 500 was already too high")
+        );
+        assert_eq!(
+            log.check(2025, 1, 1, "500").as_deref(),
+            Some("500 was already submitted and was too high")
+        );
+        assert_eq!(
+            log.check(2025, 1, 1, "99").as_deref(),
+            Some("99 is too low, 100 was already too low")
+        );
+        assert_eq!(
+            log.check(2025, 1, 1, "250").as_deref(),
+            Some("250 was already submitted and was wrong")
+        );
+        assert_eq!(log.check(2025, 1, 1, "300"), None);
+        // the bounds only apply to the same part, and to numbers
+        assert_eq!(log.check(2025, 1, 2, "600"), None);
+        assert_eq!(log.check(2025, 1, 1, "abc"), None);
+
+        assert_eq!(
+            log.check(2025, 2, 1, "1").as_deref(),
+            Some("Part 1 of day 2 was already solved with 1234")
+        );
+        assert_eq!(
+            log.check(2025, 2, 2, "abc").as_deref(),
+            Some("abc was already submitted and was wrong")
+        );
+        assert_eq!(log.check(2024, 1, 1, "600"), None);
+    }
+
+    #[test]
+    fn malformed_log_is_an_error() {
+        let e = SubmissionLog::parse(&format!("{LOG}\n2025\t1\t1\t500\n"))
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            e.to_string(),
+            format!("Malformed line 8 in {SUBMISSIONS_PATH}")
+        );
+    }
+
+    #[test]
+    fn posts_answer() {
+        let page = verdict_page("That's not the right answer; your answer is too low.");
+        let server = StubServer::serve(vec![(200, &page)]);
+
+        let response = server.client().post_answer(2025, 1, 2, "42").unwrap();
+
+        assert_eq!(parse_verdict(&response), Verdict::TooLow);
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2025/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=42"));
+    }
+
+    #[test]
+    fn server_errors_arent_retried_when_posting() {
+        let server = StubServer::serve(vec![(502, "")]);
+
+        let e = server.client().post_answer(2025, 1, 1, "42").unwrap_err();
+
+        assert!(format!("{e:#}").contains("giving up after 0 retries"));
+        assert_eq!(server.requests().len(), 1);
+    }
+}
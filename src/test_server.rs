@@ -0,0 +1,89 @@
+//! A stand-in for the AoC website for tests, which answers requests with canned responses and records them
+
+use std::{
+    io::{Read, Write},
+    net::TcpListener,
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use crate::client::AocClient;
+
+pub const TOKEN: &str = "test-token";
+pub const USER_AGENT: &str = "aoc-tests";
+
+pub struct StubServer {
+    pub base_url: String,
+    requests: JoinHandle<Vec<String>>,
+}
+
+impl StubServer {
+    /// Answers one request with each of the responses (status and body) in order, then stops listening
+    pub fn serve(responses: Vec<(u16, &str)>) -> StubServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let responses: Vec<(u16, String)> = responses
+            .into_iter()
+            .map(|(status, body)| (status, body.to_string()))
+            .collect();
+
+        let requests = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let request = read_request(&mut stream);
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                    request
+                })
+                .collect()
+        });
+
+        StubServer { base_url, requests }
+    }
+
+    /// A client for the server that doesn't wait between requests or before retrying
+    pub fn client(&self) -> AocClient {
+        // the token is padded to check that it's trimmed
+        AocClient::with_base_url(&self.base_url, &format!(" {TOKEN}\n"), USER_AGENT)
+            .unwrap()
+            .with_delays(Duration::ZERO, Duration::ZERO)
+    }
+
+    /// Waits for every response to be sent, and returns the requests they answered
+    pub fn requests(self) -> Vec<String> {
+        self.requests.join().unwrap()
+    }
+}
+
+// the request's head and body, going by its Content-Length
+fn read_request(stream: &mut impl Read) -> String {
+    let mut request = vec![];
+    let mut buffer = [0; 4096];
+    loop {
+        let read = stream.read(&mut buffer).unwrap();
+        request.extend_from_slice(&buffer[..read]);
+        let text = String::from_utf8_lossy(&request);
+        if let Some(head_end) = text.find("\r\n\r\n") {
+            let content_length = text[..head_end]
+                .lines()
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    name.eq_ignore_ascii_case("content-length")
+                        .then(|| value.trim().parse().unwrap())
+                })
+                .unwrap_or(0);
+            if request.len() >= head_end + 4 + content_length {
+                return text.into_owned();
+            }
+        }
+        if read == 0 {
+            return text.into_owned();
+        }
+    }
+}
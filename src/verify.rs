@@ -0,0 +1,120 @@
+use std::fmt::{self, Display};
+
+use crate::{
+    answers::Answers,
+    days::Answer,
+    runner::{Failure, RunReport},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    // there's no recorded answer to compare to
+    Missing,
+    // the part has no solution yet, and no recorded answer
+    Unsolved,
+    // the part has no puzzle, so there's nothing to record
+    NotApplicable,
+    // the part or day couldn't be run
+    Failed(Failure),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Pass => "PASS",
+            Status::Fail => "FAIL",
+            Status::Missing => "MISSING",
+            Status::Unsolved => "UNSOLVED",
+            Status::NotApplicable => "N/A",
+            Status::Failed(Failure::Error) => "ERROR",
+            Status::Failed(Failure::Panic) => "PANIC",
+            Status::Failed(Failure::Timeout) => "TIMEOUT",
+        };
+        write!(f, "{status}")
+    }
+}
+
+/// One part's answer compared to the recorded one, or a day that couldn't be run (without a part)
+pub struct Verification {
+    pub day: u32,
+    pub part: Option<u8>,
+    pub status: Status,
+    pub expected: Option<Answer>,
+    // None if the part or day couldn't be run
+    pub actual: Option<Answer>,
+}
+
+pub struct VerifyReport {
+    pub verifications: Vec<Verification>,
+}
+
+impl VerifyReport {
+    pub fn count(&self, status: Status) -> usize {
+        self.verifications
+            .iter()
+            .filter(|verification| verification.status == status)
+            .count()
+    }
+
+    // the parts and days that couldn't be run, whichever way they failed
+    pub fn failed_to_run(&self) -> usize {
+        self.verifications
+            .iter()
+            .filter(|verification| matches!(verification.status, Status::Failed(_)))
+            .count()
+    }
+
+    // whether every recorded answer matched, and every day with recorded answers could be run
+    pub fn succeeded(&self) -> bool {
+        self.count(Status::Fail) == 0 && self.failed_to_run() == 0
+    }
+}
+
+/// Compares the answers in a run's report to the answers recorded in answers.toml, or their example answers
+/// if the days were run on their examples.
+pub fn verify(answers: &Answers, report: &RunReport, example: bool) -> VerifyReport {
+    let mut verifications = vec![];
+    for day in &report.days {
+        let parts = match &day.parts {
+            Ok(parts) => parts,
+            Err(e) => {
+                // a day without any recorded answers has nothing that could have regressed
+                let status = match answers.has_day(day.year, day.day, example) {
+                    true => Status::Failed(Failure::of(e)),
+                    false => Status::Missing,
+                };
+                verifications.push(Verification {
+                    day: day.day,
+                    part: None,
+                    status,
+                    expected: None,
+                    actual: None,
+                });
+                continue;
+            }
+        };
+
+        for part in parts {
+            let expected = answers.expected(day.year, day.day, part.part, example);
+            let status = match (&expected, &part.answer) {
+                (_, Err(e)) => Status::Failed(Failure::of(e)),
+                (Some(expected), Ok(answer)) if expected == answer => Status::Pass,
+                (Some(_), _) => Status::Fail,
+                (None, Ok(Answer::Unsolved)) => Status::Unsolved,
+                (None, Ok(Answer::NotApplicable)) => Status::NotApplicable,
+                (None, _) => Status::Missing,
+            };
+            verifications.push(Verification {
+                day: day.day,
+                part: Some(part.part),
+                status,
+                expected,
+                actual: part.answer.as_ref().ok().cloned(),
+            });
+        }
+    }
+
+    VerifyReport { verifications }
+}